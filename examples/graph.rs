@@ -1,28 +1,13 @@
-use image::{Delay, Frame, ImageBuffer, Rgba, codecs::gif::GifEncoder};
-use larnt::{Primitive, Sphere, Vector, new_transformed_cylinder, radians, render};
-use std::{fs::File, time::Duration};
+use larnt::{
+    Paths, Primitive, Sphere, Vector, anim::Timeline, new_transformed_cylinder, radians, render,
+};
 
-fn save_gif_from_iter(
-    frames_iter: impl Iterator<Item = ImageBuffer<Rgba<u8>, Vec<u8>>>,
-    output_path: &str,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let file = File::create(output_path)?;
-    let mut encoder = GifEncoder::new(file);
+const WIDTH: f64 = 750.0;
+const HEIGHT: f64 = 750.0;
 
-    encoder.set_repeat(image::codecs::gif::Repeat::Infinite)?;
-
-    let gif_frames = frames_iter.map(|rgba_img| {
-        let delay = Delay::from_saturating_duration(Duration::from_millis(50));
-        Frame::from_parts(rgba_img, 0, 0, delay)
-    });
-
-    encoder.encode_frames(gif_frames)?;
-    Ok(())
-}
-
-fn render_frame(frame: i32) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
-    let cx = radians(frame as f64).cos();
-    let cy = radians(frame as f64).sin();
+fn render_frame(t: f64) -> Paths<Vector> {
+    let cx = radians(360.0 * t).cos();
+    let cy = radians(360.0 * t).sin();
     let mut shapes = Vec::new();
     let eye = Vector::new(cx, cy, 0.0).mul_scalar(8.0);
 
@@ -94,17 +79,19 @@ fn render_frame(frame: i32) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
         shapes.push(cylinder);
     }
 
-    let (width, height) = (750.0, 750.0);
-    let paths = render(shapes)
+    render(shapes)
         .eye(eye)
-        .width(width)
-        .height(height)
+        .width(WIDTH)
+        .height(HEIGHT)
         .fovy(60.0)
-        .call();
-    paths.to_image(width, height).linewidth(2.5).call()
+        .call()
 }
 
 fn main() {
-    let image_iter = (0..360).step_by(3).map(|i| render_frame(i));
-    save_gif_from_iter(image_iter, "output.gif").unwrap();
+    Timeline::builder(120, render_frame)
+        .width(WIDTH)
+        .height(HEIGHT)
+        .build()
+        .write_to_gif("output.gif")
+        .unwrap();
 }
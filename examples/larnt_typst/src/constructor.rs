@@ -355,7 +355,11 @@ impl LnShape {
                         Err(e) => return Err(e),
                     }
                 } else {
-                    larnt::TransformedShape::new(shape.to_shape()?, matrix.to_matrix()).into()
+                    let matrix = matrix.to_matrix();
+                    if matrix.try_inverse().is_none() {
+                        return Err("transformation matrix is singular".to_string());
+                    }
+                    larnt::TransformedShape::new(shape.to_shape()?, matrix).into()
                 }
             }
         })
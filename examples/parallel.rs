@@ -0,0 +1,57 @@
+//! Times rendering of a city block scene, with `par_render` when the `rayon`
+//! feature is enabled.
+//!
+//! Compare the single-threaded and parallel builds with:
+//!
+//! ```sh
+//! cargo run --release --example parallel
+//! cargo run --release --example parallel --features rayon
+//! ```
+use larnt::{Camera, Cube, Vector};
+use rand::{Rng, SeedableRng, rngs::SmallRng};
+use std::time::Instant;
+
+fn main() {
+    let mut rng = SmallRng::seed_from_u64(42);
+    let mut shapes = Vec::new();
+    let n = 100;
+
+    for x in -n..=n {
+        for y in -n..=n {
+            let p = rng.random::<f64>() * 0.25 + 0.2;
+            let fx = x as f64;
+            let fy = y as f64;
+            let fz = rng.random::<f64>() * 3.0 + 1.0;
+
+            let shape = Cube::builder(
+                Vector::new(fx - p, fy - p, 0.0),
+                Vector::new(fx + p, fy + p, fz),
+            )
+            .build();
+            shapes.push(shape);
+        }
+    }
+
+    let camera = Camera::builder(Vector::new(13.75, 6.25, 18.0))
+        .center(Vector::new(-8.0, -10.0, 4.0))
+        .fovy(65.0)
+        .build();
+
+    let start = Instant::now();
+    #[cfg(feature = "rayon")]
+    let paths = larnt::par_render(shapes, &camera);
+    #[cfg(not(feature = "rayon"))]
+    let paths = larnt::render(shapes)
+        .eye(camera.eye)
+        .center(camera.center)
+        .fovy(camera.fovy)
+        .call();
+    let elapsed = start.elapsed();
+
+    println!(
+        "rendered {} paths in {:.3?} (rayon: {})",
+        paths.len(),
+        elapsed,
+        cfg!(feature = "rayon")
+    );
+}
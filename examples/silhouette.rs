@@ -0,0 +1,54 @@
+//! Renders the same scene with its textures and as a silhouette.
+//!
+//! Writes `out.png` with the textured shapes and `out_silhouette.png` with
+//! only the outlines of the overlapping shapes.
+use larnt::{
+    Cube, CubeTexture, Cylinder, CylinderTexture, Scene, Sphere, SphereTexture, Torus, Vector,
+};
+
+fn main() {
+    let mut scene = Scene::new();
+    scene.add(
+        Sphere::builder(Vector::new(0.0, 0.0, 1.0), 1.0)
+            .texture(SphereTexture::lat_lng().call())
+            .build(),
+    );
+    scene.add(
+        Cube::builder(Vector::new(-2.0, -2.0, -0.5), Vector::new(2.0, 2.0, 0.0))
+            .texture(CubeTexture::striped().stripes(20).call())
+            .build(),
+    );
+    scene.add(
+        Cylinder::builder(0.3, 0.0, 2.5)
+            .texture(CylinderTexture::striped().call())
+            .build(),
+    );
+    scene.add(
+        Torus::builder(
+            Vector::new(0.0, 0.0, 1.0),
+            Vector::new(0.0, 0.0, 1.0),
+            1.4,
+            0.25,
+        )
+        .build(),
+    );
+
+    let (width, height) = (1024.0, 1024.0);
+    let eye = Vector::new(5.0, 3.0, 3.0);
+
+    let paths = scene.render().eye(eye).width(width).height(height).call();
+    paths
+        .write_to_png("out.png", width, height)
+        .expect("Failed to write PNG");
+
+    let outline = scene
+        .render()
+        .eye(eye)
+        .width(width)
+        .height(height)
+        .silhouette(true)
+        .call();
+    outline
+        .write_to_png("out_silhouette.png", width, height)
+        .expect("Failed to write PNG");
+}
@@ -1,26 +1,4 @@
-use std::{fs::File, time::Duration};
-
-use image::{Delay, Frame, ImageBuffer, Rgba, codecs::gif::GifEncoder};
-use larnt::{BBox, Matrix, Plane, TransformedShape, Vector, load_obj};
-
-fn save_gif_from_iter(
-    frames_iter: impl Iterator<Item = ImageBuffer<Rgba<u8>, Vec<u8>>>,
-    output_path: &str,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let file = File::create(output_path)?;
-    let mut encoder = GifEncoder::new(file);
-
-    encoder.set_repeat(image::codecs::gif::Repeat::Infinite)?;
-
-    let gif_frames = frames_iter.map(|rgba_img| {
-        let delay = Delay::from_saturating_duration(Duration::from_millis(50));
-
-        Frame::from_parts(rgba_img, 0, 0, delay)
-    });
-
-    encoder.encode_frames(gif_frames)?;
-    Ok(())
-}
+use larnt::{BBox, Matrix, Plane, TransformedShape, Vector, anim::Timeline, load_obj};
 
 fn main() {
     let mesh = load_obj("examples/suzanne.obj").expect("Failed to load OBJ");
@@ -31,18 +9,16 @@ fn main() {
     let mesh = TransformedShape::new(mesh, matrix);
     let slices = 128;
     let size = 1024.0;
-    save_gif_from_iter(
-        (0..slices).map(|i| {
-            let p = (i as f64 / (slices - 1) as f64) * 2.0 - 1.0;
-            let point = Vector::new(0.0, 0.0, p);
-            let plane = Plane::new(point, Vector::new(0.0, 0.0, 1.0));
-            let paths = plane.intersect_mesh(&mesh);
-            let transform = Matrix::scale(Vector::new(size / 2.0, size / 2.0, 1.0))
-                .translated(Vector::new(size / 2.0, size / 2.0, 0.0));
-            let paths = paths.transform(&transform);
-            paths.to_image(size, size).linewidth(2.5).call()
-        }),
-        "output.gif",
-    )
+    let transform = Matrix::scale(Vector::new(size / 2.0, size / 2.0, 1.0))
+        .translated(Vector::new(size / 2.0, size / 2.0, 0.0));
+    Timeline::builder(slices, |t| {
+        let point = Vector::new(0.0, 0.0, t * 2.0 - 1.0);
+        let plane = Plane::new(point, Vector::new(0.0, 0.0, 1.0));
+        plane.intersect_mesh_contours(&mesh).transform(&transform)
+    })
+    .width(size)
+    .height(size)
+    .build()
+    .write_to_gif("output.gif")
     .expect("Failed to save GIF");
 }
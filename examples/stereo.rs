@@ -0,0 +1,61 @@
+//! Renders a stereo pair of a small scene.
+//!
+//! Writes `out.png` with the pair side by side for cross-eyed viewing and
+//! `out.gif`, a "wiggle" animation alternating between the two eyes.
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame};
+use larnt::{Cube, CubeTexture, Matrix, Scene, Sphere, SphereTexture, Vector};
+use std::fs::File;
+use std::time::Duration;
+
+fn main() {
+    let mut scene = Scene::new();
+    scene.add(
+        Cube::builder(Vector::new(-2.0, -2.0, -0.5), Vector::new(2.0, 2.0, 0.0))
+            .texture(CubeTexture::striped().stripes(16).call())
+            .build(),
+    );
+    for (i, &(x, y)) in [(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)]
+        .iter()
+        .enumerate()
+    {
+        let r = 0.3 + 0.1 * i as f64;
+        scene.add(
+            Sphere::builder(Vector::new(x, y, r), r)
+                .texture(SphereTexture::lat_lng().call())
+                .build(),
+        );
+    }
+
+    let (width, height) = (600.0, 600.0);
+    let (left, right) = scene
+        .render_stereo()
+        .eye(Vector::new(6.0, 3.0, 4.0))
+        .ipd(0.25)
+        .width(width)
+        .height(height)
+        .call();
+
+    let mut pair = right.clone();
+    pair.extend(
+        left.clone()
+            .transform(&Matrix::translate(Vector::new(width, 0.0, 0.0))),
+    );
+    pair.write_to_png("out.png", 2.0 * width, height)
+        .expect("Failed to write PNG");
+
+    let mut encoder = GifEncoder::new(File::create("out.gif").expect("Failed to create GIF"));
+    encoder
+        .set_repeat(Repeat::Infinite)
+        .expect("Failed to write GIF");
+    let frames = [left, right].map(|paths| {
+        let image = paths.to_image(width, height).linewidth(2.0).call();
+        Frame::from_parts(
+            image,
+            0,
+            0,
+            Delay::from_saturating_duration(Duration::from_millis(150)),
+        )
+    });
+    encoder.encode_frames(frames).expect("Failed to write GIF");
+}
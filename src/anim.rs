@@ -0,0 +1,120 @@
+//! Animated GIF output.
+//!
+//! This module provides the [`Timeline`] helper, available with the optional
+//! `gif` feature, which renders one frame per time step and encodes them as a
+//! looping GIF.
+//!
+//! # Example
+//!
+//! ```
+//! use larnt::{Cube, Matrix, TransformedShape, Vector, anim::Timeline, radians, render};
+//!
+//! // A cube making a quarter turn, which loops seamlessly
+//! let timeline = Timeline::builder(8, |t| {
+//!     let cube = Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build();
+//!     let m = Matrix::rotate(Vector::new(0.0, 0.0, 1.0), radians(90.0 * t));
+//!     render(vec![TransformedShape::new(cube, m)])
+//!         .eye(Vector::new(4.0, 3.0, 2.0))
+//!         .width(128.0)
+//!         .height(128.0)
+//!         .call()
+//! })
+//! .width(128.0)
+//! .height(128.0)
+//! .build();
+//!
+//! timeline.write_to_gif("output.gif").expect("Failed to write GIF");
+//! ```
+
+use crate::path::Paths;
+use crate::vector::Vector;
+use bon::Builder;
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame, Rgba};
+use std::fs::File;
+
+/// A sequence of frames drawn by a function of time.
+///
+/// Frame `i` of `frames` is drawn by calling `frame` with `t = i / frames`,
+/// so `t` runs from `0` up to but excluding `1`, and a function with the same
+/// paths at `0` and `1` loops without a repeated frame.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Paths, Vector, anim::Timeline};
+///
+/// // A line sweeping across the canvas
+/// let timeline = Timeline::builder(4, |t| {
+///     let mut paths = Paths::new();
+///     let x = 10.0 + 80.0 * t;
+///     paths.new_path().extend([Vector::new(x, 10.0, 0.0), Vector::new(x, 90.0, 0.0)]);
+///     paths
+/// })
+/// .width(100.0)
+/// .height(100.0)
+/// .fps(10)
+/// .build();
+///
+/// let xs: Vec<f64> = timeline.paths().map(|p| p.bounding_box().min.x).collect();
+/// assert_eq!(xs, [10.0, 30.0, 50.0, 70.0]);
+/// ```
+#[derive(Builder)]
+pub struct Timeline<F>
+where
+    F: Fn(f64) -> Paths<Vector>,
+{
+    /// The number of frames.
+    #[builder(start_fn)]
+    pub frames: usize,
+    /// Draws the 2D paths of the frame at time `t`, in `[0, 1)`.
+    #[builder(start_fn)]
+    pub frame: F,
+    /// The frame rate, in frames per second.
+    #[builder(default = 20)]
+    pub fps: u32,
+    /// The width of the frames, in pixels.
+    #[builder(default = 1024.0)]
+    pub width: f64,
+    /// The height of the frames, in pixels.
+    #[builder(default = 1024.0)]
+    pub height: f64,
+    /// The width of the lines, as in [`Paths::to_image`].
+    #[builder(default = 2.5)]
+    pub linewidth: f64,
+    /// The background color.
+    #[builder(default = Rgba([255, 255, 255, 255]))]
+    pub background: Rgba<u8>,
+    /// The line color.
+    #[builder(default = Rgba([0, 0, 0, 255]))]
+    pub foreground: Rgba<u8>,
+}
+
+impl<F> Timeline<F>
+where
+    F: Fn(f64) -> Paths<Vector>,
+{
+    /// Returns the paths of every frame, in order.
+    pub fn paths(&self) -> impl Iterator<Item = Paths<Vector>> + '_ {
+        (0..self.frames).map(|i| (self.frame)(i as f64 / self.frames as f64))
+    }
+
+    /// Draws every frame and writes them to a GIF file that loops forever.
+    ///
+    /// Frames are drawn and encoded one at a time, so only one image is kept
+    /// in memory.
+    pub fn write_to_gif(&self, path: &str) -> Result<(), image::ImageError> {
+        let mut encoder = GifEncoder::new(File::create(path)?);
+        encoder.set_repeat(Repeat::Infinite)?;
+        let delay = Delay::from_numer_denom_ms(1000, self.fps.max(1));
+        encoder.encode_frames(self.paths().map(|paths| {
+            let image = paths
+                .to_image(self.width, self.height)
+                .linewidth(self.linewidth)
+                .background(self.background)
+                .foreground(self.foreground)
+                .call();
+            Frame::from_parts(image, 0, 0, delay)
+        }))
+    }
+}
@@ -1,4 +1,5 @@
 use crate::axis::Axis;
+use crate::path::Paths;
 use crate::ray::Ray;
 use crate::shape::Shape;
 use crate::vector::Vector;
@@ -47,6 +48,41 @@ impl BBox {
         self.max.sub(self.min)
     }
 
+    /// Returns the 12 edges of the box, e.g. to draw it for debugging.
+    ///
+    /// See [`BoxOutline`](crate::BoxOutline) to add the edges to a scene.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{BBox, Vector};
+    ///
+    /// let bx = BBox::new(Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 2.0, 3.0));
+    /// let edges = bx.wireframe_paths();
+    /// assert_eq!(edges.len(), 12);
+    /// let length: f64 = edges.iter_paths().map(|edge| edge[0].distance(edge[1])).sum();
+    /// assert!((length - 4.0 * (1.0 + 2.0 + 3.0)).abs() < 1e-9);
+    /// ```
+    pub fn wireframe_paths(&self) -> Paths<Vector> {
+        let corner = |i: usize| {
+            Vector::new(
+                [self.min.x, self.max.x][i & 1],
+                [self.min.y, self.max.y][(i >> 1) & 1],
+                [self.min.z, self.max.z][(i >> 2) & 1],
+            )
+        };
+        let mut paths = Paths::new();
+        for i in 0..8 {
+            // Connect each corner to the neighbours with one more max coordinate
+            for bit in [1, 2, 4] {
+                if i & bit == 0 {
+                    paths.new_path().extend([corner(i), corner(i | bit)]);
+                }
+            }
+        }
+        paths
+    }
+
     pub fn contains(&self, v: Vector) -> bool {
         self.min.x <= v.x
             && self.max.x >= v.x
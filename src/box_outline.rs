@@ -0,0 +1,74 @@
+//! Box outline primitive.
+//!
+//! This module provides the [`BoxOutline`] shape, the edges of an axis-aligned
+//! box drawn without hiding anything, e.g. to show the bounding box of a shape
+//! while debugging a scene.
+//!
+//! # Example
+//!
+//! ```
+//! use larnt::{BoxOutline, Primitive, Shape, Sphere, Vector, render};
+//!
+//! let sphere = Sphere::builder(Vector::new(0.0, 0.0, 0.0), 1.0).build();
+//! let outline = BoxOutline::new(sphere.bounding_box());
+//!
+//! let paths = render::<Primitive>(vec![sphere.into(), outline.into()])
+//!     .eye(Vector::new(4.0, 3.0, 2.0))
+//!     .call();
+//! ```
+
+use crate::bounding_box::BBox;
+use crate::hit::Hit;
+use crate::path::Paths;
+use crate::ray::Ray;
+use crate::shape::{RenderArgs, Shape};
+use crate::vector::Vector;
+
+/// The 12 edges of an axis-aligned box.
+///
+/// The box is not a solid: the edges are hidden by the shapes in front of
+/// them, but the box does not hide its contents.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{BBox, BoxOutline, Ray, Shape, Vector};
+///
+/// let outline = BoxOutline::new(BBox::new(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)));
+/// let hit = outline.intersect(Ray::new(Vector::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, -1.0)));
+/// assert!(!hit.is_ok());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BoxOutline {
+    /// The box to draw.
+    pub bbox: BBox,
+}
+
+impl BoxOutline {
+    /// Creates the outline of `bbox`.
+    pub fn new(bbox: BBox) -> Self {
+        BoxOutline { bbox }
+    }
+}
+
+impl Shape for BoxOutline {
+    fn bounding_box(&self) -> BBox {
+        self.bbox
+    }
+
+    fn contains(&self, _v: Vector, _f: f64) -> bool {
+        false
+    }
+
+    fn intersect(&self, _r: Ray) -> Hit {
+        Hit::no_hit()
+    }
+
+    fn paths(&self, _args: &RenderArgs) -> Paths<Vector> {
+        self.bbox.wireframe_paths()
+    }
+
+    fn view_dependent(&self) -> bool {
+        false
+    }
+}
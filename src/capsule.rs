@@ -0,0 +1,207 @@
+//! Capsule primitive.
+//!
+//! This module provides the [`Capsule`] shape (aligned along the Z axis), a
+//! cylinder body closed by two hemispherical caps. It renders as a silhouette
+//! from the camera's perspective.
+//!
+//! # Example
+//!
+//! ```
+//! use larnt::{Capsule, Vector, render};
+//!
+//! // Create a capsule with radius 0.5, with its caps centered at z=0 and z=2
+//! let capsule = Capsule::builder(0.5, 0.0, 2.0).build();
+//!
+//! let paths = render(vec![capsule]).eye(Vector::new(4.0, 3.0, 2.0)).call();
+//! ```
+
+use crate::bounding_box::BBox;
+use crate::common::INF;
+use crate::cylinder::align_z_axis;
+use crate::hit::Hit;
+use crate::path::{Paths, adaptive_arc};
+use crate::ray::Ray;
+use crate::shape::{RenderArgs, Shape, TransformedShape};
+use crate::vector::Vector;
+use bon::{Builder, builder};
+use std::f64::consts::PI;
+
+/// A capsule aligned along the Z axis.
+///
+/// The capsule is defined by its radius and the Z-range of its cylinder body,
+/// the hemispherical caps extend `radius` beyond each end.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Capsule, Shape, Vector};
+///
+/// // Capsule with radius 0.5, body from z=-1 to z=1
+/// let capsule = Capsule::builder(0.5, -1.0, 1.0).build();
+/// assert!(capsule.contains(Vector::new(0.0, 0.0, 1.4), 0.0));
+/// assert!(!capsule.contains(Vector::new(0.4, 0.0, 1.4), 0.0));
+/// ```
+#[derive(Debug, Clone, Builder)]
+pub struct Capsule {
+    /// The radius of the capsule.
+    #[builder(start_fn)]
+    pub radius: f64,
+    /// The Z coordinate of the lower cap center.
+    #[builder(start_fn)]
+    pub z0: f64,
+    /// The Z coordinate of the upper cap center.
+    #[builder(start_fn)]
+    pub z1: f64,
+}
+
+impl Capsule {
+    fn paths_outline(&self, args: &RenderArgs) -> Paths<Vector> {
+        let mut result = Paths::new();
+        let r = self.radius;
+
+        // Silhouette generators of the body, as for `Cylinder`:
+        // E.x * cos(θ) + E.y * sin(θ) = r
+        let (a, b) = (args.eye.x, args.eye.y);
+        let ratio = r / (a * a + b * b).sqrt();
+        if ratio.abs() <= 1.0 {
+            let eye_azimuth = b.atan2(a);
+            let angular_offset = ratio.acos();
+            for theta in [eye_azimuth + angular_offset, eye_azimuth - angular_offset] {
+                let (x, y) = (r * theta.cos(), r * theta.sin());
+                result
+                    .new_path()
+                    .extend([Vector::new(x, y, self.z0), Vector::new(x, y, self.z1)]);
+            }
+        }
+
+        // Silhouette circle of each cap sphere, restricted to the hemisphere
+        // beyond the body (below z0 or above z1)
+        for (zc, sign) in [(self.z0, -1.0), (self.z1, 1.0)] {
+            let center = Vector::new(0.0, 0.0, zc);
+            let hyp = center.sub(args.eye).length();
+            if hyp < r {
+                continue;
+            }
+            let theta = (r / hyp).asin();
+            let adj = r / theta.tan();
+            let d = theta.cos() * adj;
+            let rr = theta.sin() * adj;
+
+            let w = center.sub(args.eye).normalize();
+            let u = w.cross(w.min_axis()).normalize();
+            let v = w.cross(u).normalize();
+            let c = args.eye.add(w.mul_scalar(d));
+
+            // sign * (z(θ) - zc) >= 0  <=>  cos(θ - φ) >= k
+            let amp = (u.z * u.z + v.z * v.z).sqrt();
+            let (alpha, beta) = if amp * rr < 1e-12 {
+                if sign * (c.z - zc) < 0.0 {
+                    continue;
+                }
+                (0.0, PI * 2.0)
+            } else {
+                let phi = v.z.atan2(u.z) + if sign < 0.0 { PI } else { 0.0 };
+                let k = -sign * (c.z - zc) / (rr * amp);
+                if k > 1.0 {
+                    continue;
+                }
+                let delta = k.max(-1.0).acos();
+                (phi - delta, phi + delta)
+            };
+
+            adaptive_arc(alpha, beta, rr, &(c, u, v), args, &mut result.new_path());
+        }
+
+        result
+    }
+}
+
+impl Shape for Capsule {
+    fn bounding_box(&self) -> BBox {
+        let r = self.radius;
+        BBox::new(
+            Vector::new(-r, -r, self.z0 - r),
+            Vector::new(r, r, self.z1 + r),
+        )
+    }
+
+    fn contains(&self, v: Vector, f: f64) -> bool {
+        let axis = Vector::new(0.0, 0.0, v.z.clamp(self.z0, self.z1));
+        v.sub(axis).length() <= self.radius + f
+    }
+
+    fn intersect(&self, ray: Ray) -> Hit {
+        let r = self.radius;
+        let o = ray.origin;
+        let d = ray.direction;
+        let mut t_best = INF;
+
+        // Cylinder body
+        let a = d.x * d.x + d.y * d.y;
+        let b = 2.0 * o.x * d.x + 2.0 * o.y * d.y;
+        let c = o.x * o.x + o.y * o.y - r * r;
+        let q = b * b - 4.0 * a * c;
+        if a > 0.0 && q >= 0.0 {
+            let s = q.sqrt();
+            for t in [(-b - s) / (2.0 * a), (-b + s) / (2.0 * a)] {
+                let z = o.z + t * d.z;
+                if t > 1e-6 && self.z0 < z && z < self.z1 {
+                    t_best = t_best.min(t);
+                }
+            }
+        }
+
+        // Hemispherical caps
+        for (zc, sign) in [(self.z0, -1.0), (self.z1, 1.0)] {
+            let to = o.sub(Vector::new(0.0, 0.0, zc));
+            let a = d.dot(d);
+            let b = to.dot(d);
+            let c = to.dot(to) - r * r;
+            let q = b * b - a * c;
+            if q < 0.0 {
+                continue;
+            }
+            let s = q.sqrt();
+            for t in [(-b - s) / a, (-b + s) / a] {
+                let z = o.z + t * d.z;
+                if t > 1e-6 && sign * (z - zc) >= 0.0 {
+                    t_best = t_best.min(t);
+                }
+            }
+        }
+
+        if t_best < INF {
+            // From the nearest point of the axis to the hit
+            let p = ray.position(t_best);
+            let axis = Vector::new(0.0, 0.0, p.z.clamp(self.z0, self.z1));
+            Hit::new(t_best).with_normal(p.sub(axis))
+        } else {
+            Hit::no_hit()
+        }
+    }
+
+    fn paths(&self, args: &RenderArgs) -> Paths<Vector> {
+        self.paths_outline(args)
+    }
+}
+
+/// Creates a capsule between two arbitrary points.
+///
+/// This is useful for drawing capsules that aren't aligned with the Z axis.
+/// The caps are centered on `v0` and `v1`, with the given radius.
+///
+/// # Arguments
+///
+/// * `v0` - Center of the first cap
+/// * `v1` - Center of the second cap
+/// * `radius` - Radius of the capsule
+#[builder]
+pub fn new_transformed_capsule<T: From<Capsule>>(
+    #[builder(start_fn)] v0: Vector,
+    #[builder(start_fn)] v1: Vector,
+    #[builder(start_fn)] radius: f64,
+) -> TransformedShape<T> {
+    let z = v1.sub(v0).length();
+    let c = Capsule::builder(radius, 0.0, z).build();
+    TransformedShape::new(c.into(), align_z_axis(v0, v1))
+}
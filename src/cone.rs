@@ -1,8 +1,9 @@
 use crate::bounding_box::BBox;
+use crate::common::{EPS, INF};
+use crate::cylinder::align_z_axis;
 use crate::hit::Hit;
-use crate::matrix::Matrix;
 use crate::path::Paths;
-use crate::path::adaptive_arc;
+use crate::path::{adaptive_arc, adaptive_arc_inner};
 use crate::ray::Ray;
 use crate::shape::{RenderArgs, Shape, TransformedShape};
 use crate::util::radians;
@@ -12,8 +13,12 @@ use std::f64::consts::PI;
 
 #[derive(Debug, Clone, Copy, Default)]
 pub enum ConeTexture {
+    /// The camera-relative silhouette: the two slant lines tangent to the
+    /// surface as seen from the eye, plus the base circle.
     #[default]
     Outline,
+    /// Lines from the base circle to the apex every `360 / n` degrees, or to
+    /// the top circle of a [`TruncatedCone`].
     Striped(u64),
 }
 
@@ -28,6 +33,26 @@ impl ConeTexture {
 /// A 3D cone shape defined by a circular base and an apex point.
 /// The cone is oriented along the z-axis, with the base centered at
 /// the origin (0,0,0) and the apex at (0,0,height).
+///
+/// The cone is a solid closed by its base disk, which hides what is behind
+/// it.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Cone, Ray, Shape, Vector};
+///
+/// let cone = Cone::builder(1.0, 2.0).build();
+///
+/// // Straight up through the center of the base
+/// let hit = cone.intersect(Ray::new(Vector::new(0.0, 0.0, -1.0), Vector::new(0.0, 0.0, 1.0)));
+/// assert!((hit.t - 1.0).abs() < 1e-9);
+/// assert_eq!(hit.normal(), Some(Vector::new(0.0, 0.0, -1.0)));
+///
+/// // From inside, the way out through the base
+/// let hit = cone.intersect(Ray::new(Vector::new(0.5, 0.0, 0.5), Vector::new(0.0, 0.0, -1.0)));
+/// assert!((hit.t - 0.5).abs() < 1e-9);
+/// ```
 #[derive(Debug, Clone, Builder)]
 pub struct Cone {
     #[builder(start_fn)]
@@ -82,15 +107,7 @@ impl Cone {
         if ratio.abs() > 1.0 {
             // Eye is inside the extended cone surface - no proper silhouette
             // Fall back to just the base circle
-            adaptive_arc(
-                0.0,
-                PI * 2.0,
-                r,
-                &cuv,
-                &args.screen_mat,
-                args.step.powi(2),
-                &mut result.new_path(),
-            );
+            adaptive_arc(0.0, PI * 2.0, r, &cuv, args, &mut result.new_path());
             return result;
         }
 
@@ -99,14 +116,14 @@ impl Cone {
         let theta1 = eye_azimuth + angular_offset;
         let theta2 = eye_azimuth - angular_offset;
 
-        // Base circle path
-        adaptive_arc(
-            0.0,
-            PI * 2.0,
+        // Front and back arcs of the base seperately to pass visibility tests
+        adaptive_arc(theta2, theta1, r, &cuv, args, &mut result.new_path());
+        adaptive_arc_inner(
+            theta1,
+            theta2 + PI * 2.0,
             r,
             &cuv,
-            &args.screen_mat,
-            args.step.powi(2),
+            args,
             &mut result.new_path(),
         );
 
@@ -144,28 +161,209 @@ impl Shape for Cone {
         let c = o.x * o.x + o.y * o.y - k * (o.z - h) * (o.z - h);
         let q = b * b - 4.0 * a * c;
 
-        if q <= 0.0 {
-            return Hit::no_hit();
+        // The base disk at z = 0
+        let mut hit = Hit::no_hit();
+        if d.z != 0.0 {
+            let tb = -o.z / d.z;
+            let p = ray.position(tb);
+            if tb > 1e-6 && p.x * p.x + p.y * p.y < r * r {
+                hit = Hit::new(tb).with_normal(Vector::new(0.0, 0.0, -1.0));
+            }
         }
 
-        let s = q.sqrt();
-        let mut t0 = (-b + s) / (2.0 * a);
-        let mut t1 = (-b - s) / (2.0 * a);
+        if q > 0.0 {
+            let s = q.sqrt();
+            for ts in [(-b + s) / (2.0 * a), (-b - s) / (2.0 * a)] {
+                if ts > 1e-6 && ts < hit.t {
+                    let p = ray.position(ts);
+                    if p.z > 0.0 && p.z < h {
+                        // The gradient of x² + y² - k(z - h)²
+                        hit = Hit::new(ts).with_normal(Vector::new(p.x, p.y, -k * (p.z - h)));
+                    }
+                }
+            }
+        }
 
-        if t0 > t1 {
-            std::mem::swap(&mut t0, &mut t1);
+        hit
+    }
+
+    fn paths(&self, args: &RenderArgs) -> Paths<Vector> {
+        match self.texture {
+            ConeTexture::Outline => self.paths_outline(args),
+            ConeTexture::Striped(num) => self.paths_striped(num),
         }
+    }
+}
 
-        if t0 > 1e-6 {
-            let p = ray.position(t0);
-            if p.z > 0.0 && p.z < h {
-                return Hit::new(t0);
+/// A truncated cone (frustum) aligned along the Z axis.
+///
+/// The base circle of radius `radius0` is centered at the origin and the top
+/// circle of radius `radius1` at `(0, 0, height)`. Equal radii give a
+/// cylinder, a zero top radius a [`Cone`]. Like a [`Cylinder`](crate::Cylinder),
+/// the surface is open at both ends. Use [`new_transformed_truncated_cone`] to place it between two
+/// arbitrary points.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Ray, Shape, TruncatedCone, Vector};
+///
+/// let frustum = TruncatedCone::builder(1.0, 0.5, 2.0).build();
+/// assert!(frustum.contains(Vector::new(0.7, 0.0, 1.0), 0.0));
+/// assert!(!frustum.contains(Vector::new(0.8, 0.0, 1.0), 0.0));
+///
+/// // The radius halfway up is 0.75
+/// let hit = frustum.intersect(Ray::new(Vector::new(3.0, 0.0, 1.0), Vector::new(-1.0, 0.0, 0.0)));
+/// assert!((hit.t - 2.25).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Builder)]
+pub struct TruncatedCone {
+    /// The radius of the base circle at `z = 0`.
+    #[builder(start_fn)]
+    pub radius0: f64,
+    /// The radius of the top circle at `z = height`.
+    #[builder(start_fn)]
+    pub radius1: f64,
+    /// The height of the truncated cone.
+    #[builder(start_fn)]
+    pub height: f64,
+    /// The texture style for the truncated cone.
+    #[builder(default)]
+    pub texture: ConeTexture,
+}
+
+impl TruncatedCone {
+    /// Returns the change of the radius per unit of height.
+    fn slope(&self) -> f64 {
+        (self.radius1 - self.radius0) / self.height
+    }
+
+    fn paths_striped(&self, num: u64) -> Paths<Vector> {
+        let mut result = Paths::new();
+        for a in (0..360).step_by((360 / num) as usize) {
+            let (sin, cos) = radians(a as f64).sin_cos();
+            result.new_path().extend([
+                Vector::new(self.radius0 * cos, self.radius0 * sin, 0.0),
+                Vector::new(self.radius1 * cos, self.radius1 * sin, self.height),
+            ]);
+        }
+        result
+    }
+
+    fn paths_outline(&self, args: &RenderArgs) -> Paths<Vector> {
+        // The surface normal at angle θ is (cos(θ), sin(θ), -k) for the slope
+        // k, so the silhouette generators are found by solving:
+        // E.x * cos(θ) + E.y * sin(θ) = r0 + k * E.z
+        // where E is the eye position, as for `Cone` and `Cylinder`.
+        let mut result = Paths::new();
+
+        let circles = [(self.radius0, 0.0), (self.radius1, self.height)];
+        let cuv = |z: f64| {
+            (
+                Vector::new(0.0, 0.0, z),
+                Vector::new(1.0, 0.0, 0.0),
+                Vector::new(0.0, 1.0, 0.0),
+            )
+        };
+
+        let a = args.eye.x;
+        let b = args.eye.y;
+        let c = self.radius0 + self.slope() * args.eye.z;
+
+        let ratio = c / (a * a + b * b).sqrt();
+        if ratio.abs() > 1.0 {
+            // The eye is inside the extended cone surface - no proper silhouette
+            for (r, z) in circles {
+                if r > 0.0 {
+                    adaptive_arc_inner(0.0, PI * 2.0, r, &cuv(z), args, &mut result.new_path());
+                }
             }
+            return result;
         }
-        if t1 > 1e-6 {
-            let p = ray.position(t1);
-            if p.z > 0.0 && p.z < h {
-                return Hit::new(t1);
+
+        let eye_azimuth = b.atan2(a);
+        let angular_offset = ratio.acos();
+        let theta1 = eye_azimuth + angular_offset;
+        let theta2 = eye_azimuth - angular_offset;
+
+        // Front and back arcs separately to pass visibility tests
+        for (func, (alpha, beta)) in [adaptive_arc, adaptive_arc_inner]
+            .iter()
+            .zip([(theta2, theta1), (theta1, theta2 + PI * 2.0)])
+        {
+            for (r, z) in circles {
+                if r > 0.0 {
+                    func(alpha, beta, r, &cuv(z), args, &mut result.new_path());
+                }
+            }
+        }
+
+        // Silhouette lines from the tangent points
+        for theta in [theta1, theta2] {
+            let (sin, cos) = theta.sin_cos();
+            result
+                .new_path()
+                .extend(circles.map(|(r, z)| Vector::new(r * cos, r * sin, z)));
+        }
+        result
+    }
+}
+
+impl Shape for TruncatedCone {
+    fn bounding_box(&self) -> BBox {
+        let r = self.radius0.max(self.radius1);
+        BBox::new(Vector::new(-r, -r, 0.0), Vector::new(r, r, self.height))
+    }
+
+    fn contains(&self, v: Vector, f: f64) -> bool {
+        if v.z < -f || v.z > self.height + f {
+            return false;
+        }
+        let r = self.radius0 + self.slope() * v.z.clamp(0.0, self.height);
+        v.x.hypot(v.y) <= r + f
+    }
+
+    fn intersect(&self, ray: Ray) -> Hit {
+        let o = ray.origin;
+        let d = ray.direction;
+        let k = self.slope();
+        let h = self.height;
+
+        // x² + y² = (r0 + k * z)² along the ray
+        let r = self.radius0 + k * o.z;
+        let a = d.x * d.x + d.y * d.y - k * k * d.z * d.z;
+        let b = 2.0 * (o.x * d.x + o.y * d.y - k * d.z * r);
+        let c = o.x * o.x + o.y * o.y - r * r;
+
+        let (mut t0, mut t1) = if a.abs() < EPS {
+            // The ray is parallel to a generator line
+            if b == 0.0 {
+                return Hit::no_hit();
+            }
+            (-c / b, INF)
+        } else {
+            let q = b * b - 4.0 * a * c;
+            if q <= 0.0 {
+                return Hit::no_hit();
+            }
+            let s = q.sqrt();
+            ((-b + s) / (2.0 * a), (-b - s) / (2.0 * a))
+        };
+
+        if t0 > t1 {
+            std::mem::swap(&mut t0, &mut t1);
+        }
+
+        for t in [t0, t1] {
+            if t > 1e-6 && t < INF {
+                let z = ray.position(t).z;
+                // The mirrored nappe beyond the apex has a negative radius
+                if z > 0.0 && z < h && self.radius0 + k * z >= 0.0 {
+                    // The gradient of x² + y² - (r0 + k z)²
+                    let p = ray.position(t);
+                    let n = Vector::new(p.x, p.y, -k * (self.radius0 + k * z));
+                    return Hit::new(t).with_normal(n);
+                }
             }
         }
         Hit::no_hit()
@@ -186,16 +384,36 @@ pub fn new_transformed_cone<T: From<Cone>>(
     #[builder(start_fn)] radius: f64,
     #[builder(default)] texture: ConeTexture,
 ) -> TransformedShape<T> {
-    let up = Vector::new(0.0, 0.0, 1.0);
-    let d = v1.sub(v0);
-    let z = d.length();
-    let a = d.normalize().dot(up).acos();
-    let m = if a != 0.0 {
-        let u = d.cross(up).normalize();
-        Matrix::rotate(u, a).translated(v0)
-    } else {
-        Matrix::translate(v0)
-    };
+    let z = v1.sub(v0).length();
     let c = Cone::builder(radius, z).texture(texture).build();
-    TransformedShape::new(c.into(), m)
+    TransformedShape::new(c.into(), align_z_axis(v0, v1))
+}
+
+/// Creates a truncated cone between two arbitrary points.
+///
+/// The base circle of radius `r0` is centered at `v0` and the top circle of
+/// radius `r1` at `v1`.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Primitive, Shape, TransformedShape, Vector, new_transformed_truncated_cone};
+///
+/// // A lamp shade, wider at the bottom, along the x axis
+/// let shade: TransformedShape<Primitive> =
+///     new_transformed_truncated_cone(Vector::new(0.0, 0.0, 0.0), 1.0, Vector::new(2.0, 0.0, 0.0), 0.5).call();
+/// assert!(shade.contains(Vector::new(0.1, 0.9, 0.0), 0.0));
+/// assert!(!shade.contains(Vector::new(1.9, 0.9, 0.0), 0.0));
+/// ```
+#[builder]
+pub fn new_transformed_truncated_cone<T: From<TruncatedCone>>(
+    #[builder(start_fn)] v0: Vector,
+    #[builder(start_fn)] r0: f64,
+    #[builder(start_fn)] v1: Vector,
+    #[builder(start_fn)] r1: f64,
+    #[builder(default)] texture: ConeTexture,
+) -> TransformedShape<T> {
+    let z = v1.sub(v0).length();
+    let c = TruncatedCone::builder(r0, r1, z).texture(texture).build();
+    TransformedShape::new(c.into(), align_z_axis(v0, v1))
 }
@@ -2,6 +2,7 @@
 //!
 //! This module provides functions for combining shapes using boolean operations:
 //!
+//! - [`new_union`]: Creates a shape that is the union of multiple shapes
 //! - [`new_intersection`]: Creates a shape that is the intersection of multiple shapes
 //! - [`new_difference`]: Creates a shape that subtracts shapes from the first one
 //!
@@ -30,6 +31,8 @@ use crate::vector::Vector;
 /// Boolean operation type for CSG.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Op {
+    /// Union: keeps the volume that is inside either shape.
+    Union,
     /// Intersection: keeps only the volume that is inside both shapes.
     Intersection,
     /// Difference: subtracts the second shape from the first.
@@ -67,6 +70,36 @@ where
         .unwrap_or_else(|| EmptyShape.into())
 }
 
+/// Creates a union of multiple shapes.
+///
+/// The resulting shape contains the volume that is inside any input shape.
+/// Unlike rendering the shapes side by side, the parts of each shape's
+/// texture that lie inside another shape are removed, so overlapping solids
+/// read as a single object.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Paths, Primitive, Shape, Sphere, Vector, new_union, render};
+///
+/// let a: Primitive = Sphere::builder(Vector::new(-0.5, 0.0, 0.0), 1.0).build().into();
+/// let b: Primitive = Sphere::builder(Vector::new(0.5, 0.0, 0.0), 1.0).build().into();
+/// let union: Primitive = new_union(vec![a, b]);
+///
+/// assert!(union.contains(Vector::new(-1.4, 0.0, 0.0), 0.0));
+/// assert!(union.contains(Vector::new(1.4, 0.0, 0.0), 0.0));
+/// assert!(!union.contains(Vector::new(0.0, 0.0, 1.0), 0.0));
+///
+/// let paths = render(vec![union]).eye(Vector::new(0.0, -4.0, 3.0)).call();
+/// assert!(paths.len() > 0);
+/// ```
+pub fn new_union<T>(shapes: Vec<T>) -> T
+where
+    T: Shape + From<BooleanShape<T>> + From<EmptyShape>,
+{
+    new_boolean_shape(Op::Union, shapes)
+}
+
 /// Creates an intersection of multiple shapes.
 ///
 /// The resulting shape contains only the volume that is inside all input shapes.
@@ -116,12 +149,19 @@ impl<T: Shape> Shape for BooleanShape<T> {
     fn bounding_box(&self) -> BBox {
         let a = self.a.bounding_box();
         let b = self.b.bounding_box();
-        a.extend(b)
+        match self.op {
+            Op::Union => a.extend(b),
+            // The result lies within both boxes, which keeps unbounded
+            // operands such as a `HalfSpace` from growing it
+            Op::Intersection => BBox::new(a.min.max(b.min), a.max.min(b.max)),
+            Op::Difference => a,
+        }
     }
 
     fn contains(&self, v: Vector, _f: f64) -> bool {
         let f = 1e-3;
         match self.op {
+            Op::Union => self.a.contains(v, f) || self.b.contains(v, f),
             Op::Intersection => self.a.contains(v, f) && self.b.contains(v, f),
             Op::Difference => self.a.contains(v, f) && !self.b.contains(v, -f),
         }
@@ -129,7 +169,11 @@ impl<T: Shape> Shape for BooleanShape<T> {
 
     fn intersect(&self, r: Ray) -> Hit {
         let h1 = self.a.intersect(r);
-        let h2 = self.b.intersect(r);
+        let mut h2 = self.b.intersect(r);
+        // The surface of a subtracted shape faces into it
+        if self.op == Op::Difference {
+            h2.normal = h2.normal.map(|n| n.mul_scalar(-1.0));
+        }
         let h = h1.min(h2);
         let v = r.position(h.t);
 
@@ -141,6 +185,23 @@ impl<T: Shape> Shape for BooleanShape<T> {
     }
 
     fn paths(&self, args: &RenderArgs) -> Paths<Vector> {
+        if self.op == Op::Union {
+            // Every point on either surface is on the union's closure, so each
+            // operand's paths are clipped against the interior of the other
+            let mut p = self
+                .a
+                .paths(args)
+                .chop_adaptive(args)
+                .filter(&Outside(&*self.b));
+            p.extend(
+                self.b
+                    .paths(args)
+                    .chop_adaptive(args)
+                    .filter(&Outside(&*self.a)),
+            );
+            return p;
+        }
+
         let mut p = self.a.paths(args);
         p.extend(self.b.paths(args));
         p = p.chop_adaptive(args);
@@ -154,3 +215,16 @@ impl<T: Shape> Filter for BooleanShape<T> {
         if self.contains(v, 0.0) { Some(v) } else { None }
     }
 }
+
+/// Keeps the points that are not strictly inside a shape.
+struct Outside<'a, T>(&'a T);
+
+impl<T: Shape> Filter for Outside<'_, T> {
+    fn filter(&self, v: Vector) -> Option<Vector> {
+        if self.0.contains(v, -1e-3) {
+            None
+        } else {
+            Some(v)
+        }
+    }
+}
@@ -21,15 +21,31 @@ use crate::ray::Ray;
 use crate::shape::{RenderArgs, Shape};
 use crate::vector::Vector;
 use bon::{Builder, bon};
+use std::sync::Arc;
+
+/// A user supplied function generating the paths of a cube.
+pub type CubeTextureFn = dyn Fn(&Cube) -> Paths<Vector> + Send + Sync;
 
 /// Texture style for the cube.
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub enum CubeTexture {
-    /// Plain cube with edges only.
+    /// Plain cube with its 12 edges only.
     #[default]
     Vanilla,
-    /// Cube with striped pattern on faces.
+    /// Cube with striped pattern on faces, in addition to the 12 edges.
     Striped(u64),
+    /// Paths generated by a user supplied function, see [`CubeTexture::custom`].
+    Custom(Arc<CubeTextureFn>),
+}
+
+impl std::fmt::Debug for CubeTexture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CubeTexture::Vanilla => write!(f, "Vanilla"),
+            CubeTexture::Striped(stripes) => f.debug_tuple("Striped").field(stripes).finish(),
+            CubeTexture::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
 }
 
 #[bon]
@@ -41,18 +57,65 @@ impl CubeTexture {
     }
 }
 
+impl CubeTexture {
+    /// Create a texture whose paths are generated by `f` from the cube.
+    ///
+    /// The function must be `Send + Sync` so textured cubes can still be shared
+    /// across threads, e.g. in a [`Scene`](crate::Scene).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Cube, CubeTexture, Paths, Vector, render};
+    ///
+    /// // A cross on the top face
+    /// let cross = CubeTexture::custom(|cube: &Cube| {
+    ///     let (min, max) = (cube.min, cube.max);
+    ///     let mut paths = Paths::new();
+    ///     paths.new_path().extend([Vector::new(min.x, min.y, max.z), max]);
+    ///     paths.new_path().extend([
+    ///         Vector::new(min.x, max.y, max.z),
+    ///         Vector::new(max.x, min.y, max.z),
+    ///     ]);
+    ///     paths
+    /// });
+    /// let cube = Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0))
+    ///     .texture(cross)
+    ///     .build();
+    ///
+    /// let paths = render(vec![cube]).eye(Vector::new(1.0, 2.0, 6.0)).call();
+    /// assert_eq!(paths.len(), 2);
+    /// ```
+    pub fn custom(f: impl Fn(&Cube) -> Paths<Vector> + Send + Sync + 'static) -> Self {
+        CubeTexture::Custom(Arc::new(f))
+    }
+}
+
 /// An axis-aligned cube (rectangular cuboid).
 ///
 /// A `Cube` is defined by two opposite corners (minimum and maximum points).
-/// The default paths generated are the 12 edges of the cube.
+/// The default paths generated are the 12 edges of the cube, and a
+/// [`CubeTexture::Striped`] cube draws its stripes on top of the same edges.
 ///
 /// # Example
 ///
 /// ```
-/// use larnt::{Cube, Vector};
+/// use larnt::{Camera, Cube, CubeTexture, Shape, Vector};
 ///
 /// // Unit cube from (0,0,0) to (1,1,1)
 /// let cube = Cube::builder(Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 1.0, 1.0)).build();
+///
+/// let args = Camera::builder(Vector::new(4.0, 3.0, 2.0)).build().render_args();
+/// let edges = cube.paths(&args);
+/// assert_eq!(edges.len(), 12);
+/// assert!(edges.iter_paths().all(|edge| edge.len() == 2 && edge[0].distance(edge[1]) == 1.0));
+/// assert!(edges.iter_paths().eq(cube.bounding_box().wireframe_paths().iter_paths()));
+///
+/// let striped = Cube { texture: CubeTexture::striped().stripes(4).call(), ..cube }.paths(&args);
+/// for edge in edges.iter_paths() {
+///     let reversed = [edge[1], edge[0]];
+///     assert!(striped.iter_paths().any(|path| path == edge || path == reversed));
+/// }
 /// ```
 #[derive(Debug, Clone, Builder)]
 pub struct Cube {
@@ -92,24 +155,50 @@ impl Shape for Cube {
         let t0 = n.x.max(n.y).max(n.z);
         let t1 = f.x.min(f.y).min(f.z);
 
-        if t0 < 1e-3 && t1 > 1e-3 {
-            return Hit::new(t1);
-        }
-        if t0 >= 1e-3 && t0 < t1 {
-            return Hit::new(t0);
-        }
-        Hit::no_hit()
+        let t = if t0 < 1e-3 && t1 > 1e-3 {
+            t1
+        } else if t0 >= 1e-3 && t0 < t1 {
+            t0
+        } else {
+            return Hit::no_hit();
+        };
+        Hit::new(t).with_normal(self.normal_at(r.position(t)))
     }
 
     fn paths(&self, _args: &RenderArgs) -> Paths<Vector> {
-        match self.texture {
-            CubeTexture::Vanilla => self.paths_striped(1),
-            CubeTexture::Striped(stripes) => self.paths_striped(stripes),
+        match &self.texture {
+            CubeTexture::Vanilla => self.paths_edges(),
+            CubeTexture::Striped(stripes) => self.paths_striped(*stripes),
+            CubeTexture::Custom(f) => f(self),
         }
     }
+
+    fn view_dependent(&self) -> bool {
+        false
+    }
 }
 
 impl Cube {
+    /// Returns the outward normal of the face nearest to `p`.
+    fn normal_at(&self, p: Vector) -> Vector {
+        let faces = [
+            (p.x - self.min.x, Vector::new(-1.0, 0.0, 0.0)),
+            (self.max.x - p.x, Vector::new(1.0, 0.0, 0.0)),
+            (p.y - self.min.y, Vector::new(0.0, -1.0, 0.0)),
+            (self.max.y - p.y, Vector::new(0.0, 1.0, 0.0)),
+            (p.z - self.min.z, Vector::new(0.0, 0.0, -1.0)),
+            (self.max.z - p.z, Vector::new(0.0, 0.0, 1.0)),
+        ];
+        faces
+            .into_iter()
+            .min_by(|a, b| a.0.abs().total_cmp(&b.0.abs()))
+            .map_or_else(Vector::default, |(_, n)| n)
+    }
+
+    fn paths_edges(&self) -> Paths<Vector> {
+        BBox::new(self.min, self.max).wireframe_paths()
+    }
+
     fn paths_striped(&self, stripes: u64) -> Paths<Vector> {
         let (x1, y1, z1) = (self.min.x, self.min.y, self.min.z);
         let (x2, y2, z2) = (self.max.x, self.max.y, self.max.z);
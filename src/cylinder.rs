@@ -103,7 +103,6 @@ impl Cylinder {
         let sqrt_ab = (a * a + b * b).sqrt();
 
         let (u, v) = (Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0));
-        let step_sq = args.step.powi(2);
 
         // Compute silhouette generator angles
         let ratio = c / sqrt_ab;
@@ -116,8 +115,7 @@ impl Cylinder {
                     PI * 2.0,
                     r,
                     &(Vector::new(0.0, 0.0, z), u, v),
-                    &args.screen_mat,
-                    step_sq,
+                    args,
                     &mut result.new_path(),
                 )
             }
@@ -140,8 +138,7 @@ impl Cylinder {
                     beta,
                     r,
                     &(Vector::new(0.0, 0.0, z), u, v),
-                    &args.screen_mat,
-                    step_sq,
+                    args,
                     &mut result.new_path(),
                 )
             }
@@ -197,11 +194,11 @@ impl Shape for Cylinder {
         let z0 = o.z + t0 * d.z;
         let z1 = o.z + t1 * d.z;
 
-        if t0 > 1e-6 && self.z0 < z0 && z0 < self.z1 {
-            return Hit::new(t0);
-        }
-        if t1 > 1e-6 && self.z0 < z1 && z1 < self.z1 {
-            return Hit::new(t1);
+        for (t, z) in [(t0, z0), (t1, z1)] {
+            if t > 1e-6 && self.z0 < z && z < self.z1 {
+                let p = ray.position(t);
+                return Hit::new(t).with_normal(Vector::new(p.x, p.y, 0.0));
+            }
         }
         Hit::no_hit()
     }
@@ -232,16 +229,24 @@ pub fn new_transformed_cylinder<T: From<Cylinder>>(
     #[builder(start_fn)] radius: f64,
     #[builder(default)] texture: CylinderTexture,
 ) -> TransformedShape<T> {
+    let z = v1.sub(v0).length();
+    let c = Cylinder::builder(radius, 0.0, z).texture(texture).build();
+    TransformedShape::new(c.into(), align_z_axis(v0, v1))
+}
+
+/// Returns the matrix mapping the +Z axis starting at the origin onto the
+/// axis from `v0` towards `v1`.
+pub(crate) fn align_z_axis(v0: Vector, v1: Vector) -> Matrix {
     let up = Vector::new(0.0, 0.0, 1.0);
     let d = v1.sub(v0);
-    let z = d.length();
-    let a = d.normalize().dot(up).acos();
-    let m = if a != 0.0 {
-        let u = d.cross(up).normalize();
-        Matrix::rotate(u, a).translated(v0)
-    } else {
+    let a = d.angle_between(up);
+    let u = d.cross(up);
+    if a == 0.0 {
         Matrix::translate(v0)
-    };
-    let c = Cylinder::builder(radius, 0.0, z).texture(texture).build();
-    TransformedShape::new(c.into(), m)
+    } else if u.length_squared() == 0.0 {
+        // `d` points straight down, any horizontal axis works
+        Matrix::rotate(Vector::new(1.0, 0.0, 0.0), a).translated(v0)
+    } else {
+        Matrix::rotate(u.normalize(), a).translated(v0)
+    }
 }
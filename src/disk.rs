@@ -0,0 +1,118 @@
+//! Disk primitive.
+//!
+//! This module provides the [`Disk`] shape, a flat filled circle that occludes
+//! whatever lies behind it, e.g. to draw pads under objects on a ground plane.
+//!
+//! # Example
+//!
+//! ```
+//! use larnt::{Disk, Sphere, Vector, render};
+//! # use larnt::Primitive;
+//!
+//! // A disk lying on the XY plane under a sphere
+//! let disk = Disk::builder(Vector::new(0.0, 0.0, -1.0), Vector::new(0.0, 0.0, 1.0), 2.0).build();
+//! let sphere = Sphere::builder(Vector::new(0.0, 0.0, 0.0), 1.0).build();
+//!
+//! let paths = render::<Primitive>(vec![disk.into(), sphere.into()])
+//!     .eye(Vector::new(4.0, 3.0, 2.0))
+//!     .call();
+//! ```
+
+use crate::bounding_box::BBox;
+use crate::common::EPS;
+use crate::hit::Hit;
+use crate::path::{Paths, adaptive_arc_inner};
+use crate::ray::Ray;
+use crate::shape::{RenderArgs, Shape};
+use crate::vector::Vector;
+use bon::Builder;
+use std::f64::consts::PI;
+
+/// A flat disk defined by center, normal and radius.
+///
+/// The paths generated are the boundary circle plus `rings` evenly spaced
+/// concentric circles inside it.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Disk, Hit, Ray, Shape, Vector};
+///
+/// let disk = Disk::builder(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0), 1.0)
+///     .rings(0)
+///     .build();
+///
+/// let down = Vector::new(0.0, 0.0, -1.0);
+/// assert!(disk.intersect(Ray::new(Vector::new(0.5, 0.0, 2.0), down)).is_ok());
+/// assert!(!disk.intersect(Ray::new(Vector::new(1.5, 0.0, 2.0), down)).is_ok());
+/// ```
+#[derive(Debug, Clone, Builder)]
+pub struct Disk {
+    /// The center point of the disk.
+    #[builder(start_fn)]
+    pub center: Vector,
+    /// The normal of the plane the disk lies in.
+    #[builder(start_fn)]
+    pub normal: Vector,
+    /// The radius of the disk.
+    #[builder(start_fn)]
+    pub radius: f64,
+    /// The number of concentric rings drawn inside the boundary circle.
+    #[builder(default = 3)]
+    pub rings: usize,
+}
+
+impl Shape for Disk {
+    fn bounding_box(&self) -> BBox {
+        let n = self.normal.normalize();
+        let extent = Vector::new(
+            (1.0 - n.x * n.x).max(0.0).sqrt(),
+            (1.0 - n.y * n.y).max(0.0).sqrt(),
+            (1.0 - n.z * n.z).max(0.0).sqrt(),
+        )
+        .mul_scalar(self.radius);
+        BBox::new(self.center.sub(extent), self.center.add(extent))
+    }
+
+    fn contains(&self, _v: Vector, _f: f64) -> bool {
+        false
+    }
+
+    fn intersect(&self, r: Ray) -> Hit {
+        let d = self.normal.dot(r.direction);
+        if d > -EPS && d < EPS {
+            return Hit::no_hit();
+        }
+
+        let t = self.normal.dot(self.center.sub(r.origin)) / d;
+        if t < EPS {
+            return Hit::no_hit();
+        }
+
+        if r.position(t).distance_squared(self.center) > self.radius * self.radius {
+            return Hit::no_hit();
+        }
+
+        Hit::new(t).with_normal(self.normal)
+    }
+
+    fn paths(&self, args: &RenderArgs) -> Paths<Vector> {
+        let mut paths = Paths::new();
+        let n = self.normal.normalize();
+        let u = n.cross(n.min_axis()).normalize();
+        let v = n.cross(u).normalize();
+
+        for i in 0..=self.rings {
+            let r = self.radius * (self.rings + 1 - i) as f64 / (self.rings + 1) as f64;
+            adaptive_arc_inner(
+                0.0,
+                PI * 2.0,
+                r,
+                &(self.center, u, v),
+                args,
+                &mut paths.new_path(),
+            );
+        }
+        paths
+    }
+}
@@ -0,0 +1,217 @@
+//! Ellipsoid primitive.
+//!
+//! This module provides the [`Ellipsoid`] shape, an axis-aligned sphere stretched
+//! by a per-axis radius. Textures are computed in unit-sphere space and mapped
+//! back, so the latitude/longitude grid keeps its spacing in the ellipsoid metric.
+//!
+//! # Example
+//!
+//! ```
+//! use larnt::{Ellipsoid, EllipsoidTexture, Vector, render};
+//!
+//! // Create an ellipsoid at the origin with the default outline texture
+//! let ellipsoid = Ellipsoid::builder(Vector::new(0.0, 0.0, 0.0), Vector::new(2.0, 1.0, 0.5)).build();
+//!
+//! // Or with a latitude/longitude grid
+//! let ellipsoid_grid = Ellipsoid::builder(Vector::new(3.0, 0.0, 0.0), Vector::new(0.5, 0.5, 1.5))
+//!     .texture(EllipsoidTexture::lat_lng().call())
+//!     .build();
+//!
+//! let paths = render(vec![ellipsoid, ellipsoid_grid]).eye(Vector::new(4.0, 3.0, 2.0)).call();
+//! ```
+
+use crate::hit::Hit;
+use crate::path::Paths;
+use crate::path::adaptive_arc;
+use crate::ray::Ray;
+use crate::shape::Shape;
+use crate::util::radians;
+use crate::vector::Vector;
+use crate::{bounding_box::BBox, shape::RenderArgs};
+use bon::{Builder, bon};
+use std::f64::consts::PI;
+
+/// Texture style for Ellipsoid shapes
+#[derive(Debug, Clone, Copy, Default)]
+pub enum EllipsoidTexture {
+    /// An ellipsoid that renders as a silhouette outline from the camera's perspective.
+    #[default]
+    Outline,
+    /// Latitude/longitude grid texture (default n: 10, o: 10)
+    LatLng { n: i32, o: i32 },
+}
+
+#[bon]
+impl EllipsoidTexture {
+    /// Create a latitude/longitude grid texture with the specified number of lines and offset.
+    #[builder]
+    pub fn lat_lng(#[builder(default = 10)] n: i32, #[builder(default = 10)] o: i32) -> Self {
+        EllipsoidTexture::LatLng { n, o }
+    }
+}
+
+/// An axis-aligned ellipsoid defined by center and radii.
+///
+/// The default paths generated are a silhouette outline from the camera's perspective.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Ellipsoid, EllipsoidTexture, Shape, Vector};
+///
+/// // Ellipsoid at origin, stretched along the X axis (default outline texture)
+/// let ellipsoid = Ellipsoid::builder(Vector::new(0.0, 0.0, 0.0), Vector::new(2.0, 1.0, 1.0)).build();
+/// assert!(ellipsoid.contains(Vector::new(1.9, 0.0, 0.0), 0.0));
+/// assert!(!ellipsoid.contains(Vector::new(0.0, 1.1, 0.0), 0.0));
+///
+/// // Ellipsoid with latitude/longitude grid texture
+/// let ellipsoid_grid = Ellipsoid::builder(Vector::new(0.0, 0.0, 0.0), Vector::new(2.0, 1.0, 1.0))
+///     .texture(EllipsoidTexture::lat_lng().n(15).call())
+///     .build();
+/// ```
+#[derive(Debug, Clone, Builder)]
+pub struct Ellipsoid {
+    /// The center point of the ellipsoid.
+    #[builder(start_fn)]
+    pub center: Vector,
+    /// The radius along each of the X, Y and Z axes.
+    #[builder(start_fn)]
+    pub radii: Vector,
+    /// The texture style for the ellipsoid.
+    #[builder(default)]
+    pub texture: EllipsoidTexture,
+}
+
+impl Shape for Ellipsoid {
+    fn bounding_box(&self) -> BBox {
+        BBox::new(self.center.sub(self.radii), self.center.add(self.radii))
+    }
+
+    fn contains(&self, v: Vector, f: f64) -> bool {
+        let p = v.sub(self.center).div(self.radii.add_scalar(f));
+        p.length_squared() <= 1.0
+    }
+
+    fn intersect(&self, r: Ray) -> Hit {
+        // Scale the ray into unit-sphere space, `t` is preserved by the scaling
+        let to = r.origin.sub(self.center).div(self.radii);
+        let d = r.direction.div(self.radii);
+        let a = d.dot(d);
+        let b = to.dot(d);
+        let c = to.dot(to) - 1.0;
+        let disc = b * b - a * c;
+
+        if disc > 0.0 {
+            let disc = disc.sqrt();
+            for t in [(-b - disc) / a, (-b + disc) / a] {
+                if t > 1e-2 {
+                    // The gradient of the implicit surface
+                    let p = r.position(t).sub(self.center);
+                    return Hit::new(t).with_normal(p.div(self.radii).div(self.radii));
+                }
+            }
+        }
+        Hit::no_hit()
+    }
+
+    fn paths(&self, args: &RenderArgs) -> Paths<Vector> {
+        match self.texture {
+            EllipsoidTexture::Outline => self.paths_outline(args),
+            EllipsoidTexture::LatLng { n, o } => self.paths_lat_lng(args, n, o),
+        }
+    }
+}
+
+impl Ellipsoid {
+    /// Maps a unit-sphere circle `(c, u, v)` onto the ellipsoid surface.
+    fn to_world(&self, (c, u, v): (Vector, Vector, Vector)) -> (Vector, Vector, Vector) {
+        (
+            self.center.add(c.mul(self.radii)),
+            u.mul(self.radii),
+            v.mul(self.radii),
+        )
+    }
+
+    /// Outline texture: renders as a silhouette outline from the camera's perspective.
+    ///
+    /// The silhouette circle of the unit sphere, seen from the eye in unit-sphere
+    /// space, maps to the silhouette of the ellipsoid.
+    fn paths_outline(&self, args: &RenderArgs) -> Paths<Vector> {
+        let mut paths = Paths::new();
+
+        let eye = args.eye.sub(self.center).div(self.radii);
+        let hyp = eye.length();
+        if hyp < 1.0 {
+            return Paths::new();
+        }
+        let theta = (1.0 / hyp).asin();
+        let adj = 1.0 / theta.tan();
+        let d = theta.cos() * adj;
+        let r = theta.sin() * adj;
+
+        let w = eye.mul_scalar(-1.0).normalize();
+        let u = w.cross(w.min_axis()).normalize();
+        let v = w.cross(u).normalize();
+        let c = eye.add(w.mul_scalar(d));
+
+        adaptive_arc(
+            0.0,
+            PI * 2.,
+            r,
+            &self.to_world((c, u, v)),
+            args,
+            &mut paths.new_path(),
+        );
+        paths
+    }
+
+    /// Latitude/longitude grid texture
+    fn paths_lat_lng(&self, args: &RenderArgs, n: i32, o: i32) -> Paths<Vector> {
+        let mut paths = Paths::new();
+
+        // Latitude lines
+        {
+            let mut lat = -90 + o;
+            while lat <= 90 - o {
+                let latr = radians(lat as f64);
+                let c = Vector::new(0.0, 0.0, latr.sin());
+                let (u, v) = (Vector::new(1., 0., 0.), Vector::new(0., 1., 0.));
+
+                adaptive_arc(
+                    0.0,
+                    PI * 2.0,
+                    latr.cos(),
+                    &self.to_world((c, u, v)),
+                    args,
+                    &mut paths.new_path(),
+                );
+                lat += n;
+            }
+        }
+
+        // Longitude lines
+        {
+            let mut lng = 0;
+            let u = Vector::new(0.0, 0.0, 1.0);
+            while lng < 360 {
+                let v = {
+                    let lngr = radians(lng as f64);
+                    Vector::new(lngr.cos(), lngr.sin(), 0.0)
+                };
+                let [alpha, beta] = [o, 180 - o].map(|x| radians(x as f64));
+
+                adaptive_arc(
+                    alpha,
+                    beta,
+                    1.0,
+                    &self.to_world((Vector::default(), u, v)),
+                    args,
+                    &mut paths.new_path(),
+                );
+                lng += n;
+            }
+        }
+
+        paths
+    }
+}
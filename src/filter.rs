@@ -17,6 +17,95 @@ pub static CLIP_BOX: BBox = BBox {
 
 pub trait Filter {
     fn filter(&self, v: Vector) -> Option<Vector>;
+
+    /// Combines this filter with another one, keeping the points both keep.
+    ///
+    /// See [`AndFilter`].
+    fn and<F: Filter>(self, other: F) -> AndFilter<Self, F>
+    where
+        Self: Sized,
+    {
+        AndFilter(self, other)
+    }
+
+    /// Combines this filter with another one, keeping the points either keeps.
+    ///
+    /// See [`OrFilter`].
+    fn or<F: Filter>(self, other: F) -> OrFilter<Self, F>
+    where
+        Self: Sized,
+    {
+        OrFilter(self, other)
+    }
+}
+
+impl<F: Filter + ?Sized> Filter for &F {
+    fn filter(&self, v: Vector) -> Option<Vector> {
+        (*self).filter(v)
+    }
+}
+
+/// A filter chaining two filters.
+///
+/// The point is passed through the first filter, then its result through the
+/// second one, so it is rejected if either rejects it.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{AndFilter, BBox, BoxClip, Filter, Paths, PlaneClip, Vector};
+///
+/// let mut paths = Paths::new();
+/// paths
+///     .new_path()
+///     .extend([Vector::new(-2.0, 0.0, 0.0), Vector::new(2.0, 0.0, 0.0)]);
+///
+/// let box_clip = BoxClip::new(BBox::new(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)));
+/// let plane_clip = PlaneClip::new(Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+///
+/// let clipped = paths.chop(0.01).filter(&AndFilter(box_clip, plane_clip));
+/// assert_eq!(clipped.len(), 1);
+/// assert!(clipped.iter_paths().flatten().all(|v| (0.0..=1.0).contains(&v.x)));
+///
+/// // The same filter, written fluently
+/// let fluent = paths.chop(0.01).filter(&box_clip.and(plane_clip));
+/// assert_eq!(fluent.len(), clipped.len());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AndFilter<A, B>(pub A, pub B);
+
+impl<A: Filter, B: Filter> Filter for AndFilter<A, B> {
+    fn filter(&self, v: Vector) -> Option<Vector> {
+        self.0.filter(v).and_then(|v| self.1.filter(v))
+    }
+}
+
+/// A filter keeping the points kept by either of two filters.
+///
+/// The result of the first filter is used when it keeps the point, the second
+/// filter is only consulted otherwise.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Filter, OrFilter, PlaneClip, Vector};
+///
+/// let origin = Vector::new(0.0, 0.0, 0.0);
+/// let right = PlaneClip::new(origin, Vector::new(1.0, 0.0, 0.0));
+/// let top = PlaneClip::new(origin, Vector::new(0.0, 1.0, 0.0));
+///
+/// let either = OrFilter(right, top);
+/// assert!(either.filter(Vector::new(1.0, -1.0, 0.0)).is_some());
+/// assert!(either.filter(Vector::new(-1.0, 1.0, 0.0)).is_some());
+/// assert!(either.filter(Vector::new(-1.0, -1.0, 0.0)).is_none());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OrFilter<A, B>(pub A, pub B);
+
+impl<A: Filter, B: Filter> Filter for OrFilter<A, B> {
+    fn filter(&self, v: Vector) -> Option<Vector> {
+        self.0.filter(v).or_else(|| self.1.filter(v))
+    }
 }
 
 pub struct ClipFilter<F> {
@@ -47,3 +136,121 @@ impl<F: Fn(Vector, Vector) -> bool> Filter for ClipFilter<F> {
         Some(w)
     }
 }
+
+/// A filter keeping only the points inside a box.
+///
+/// Paths leaving the box are split, so only their inside portions remain.
+/// Filtering works point by point, chop paths first for a precise cut.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{BBox, BoxClip, Paths, Vector};
+///
+/// let mut paths = Paths::new();
+/// paths
+///     .new_path()
+///     .extend([Vector::new(-2.0, 0.0, 0.0), Vector::new(2.0, 0.0, 0.0)]);
+///
+/// let clip = BoxClip::new(BBox::new(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)));
+/// let clipped = paths.chop(0.01).filter(&clip);
+///
+/// assert_eq!(clipped.len(), 1);
+/// let path = clipped.iter_paths().next().unwrap();
+/// assert!((path[0].x + 1.0).abs() < 0.02);
+/// assert!((path[path.len() - 1].x - 1.0).abs() < 0.02);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BoxClip {
+    pub bx: BBox,
+}
+
+impl BoxClip {
+    pub fn new(bx: BBox) -> Self {
+        Self { bx }
+    }
+}
+
+impl Filter for BoxClip {
+    fn filter(&self, v: Vector) -> Option<Vector> {
+        self.bx.contains(v).then_some(v)
+    }
+}
+
+/// A filter keeping only the points on the side of a plane that `normal`
+/// points to.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Paths, PlaneClip, Vector};
+///
+/// let mut paths = Paths::new();
+/// paths
+///     .new_path()
+///     .extend([Vector::new(0.0, 0.0, -1.0), Vector::new(0.0, 0.0, 1.0)]);
+///
+/// let clip = PlaneClip::new(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+/// let clipped = paths.chop(0.1).filter(&clip);
+///
+/// assert_eq!(clipped.len(), 1);
+/// assert!(clipped.iter_paths().flatten().all(|v| v.z >= 0.0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PlaneClip {
+    pub point: Vector,
+    pub normal: Vector,
+}
+
+impl PlaneClip {
+    pub fn new(point: Vector, normal: Vector) -> Self {
+        Self { point, normal }
+    }
+}
+
+impl Filter for PlaneClip {
+    fn filter(&self, v: Vector) -> Option<Vector> {
+        (v.sub(self.point).dot(self.normal) >= 0.0).then_some(v)
+    }
+}
+
+/// A filter dropping the points farther than `max_distance` from `eye`.
+///
+/// Distances are measured in world space, so the filter must be applied to
+/// 3D paths before projection, e.g. to the paths of a shape. Filtering the
+/// screen space output of [`render`](crate::render) measures meaningless
+/// distances.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{DistanceClip, Paths, Vector};
+///
+/// // A line running away from the eye
+/// let eye = Vector::new(0.0, 0.0, 0.0);
+/// let mut paths = Paths::new();
+/// paths
+///     .new_path()
+///     .extend([Vector::new(1.0, 0.0, 0.0), Vector::new(10.0, 0.0, 0.0)]);
+///
+/// let clipped = paths.chop(0.1).filter(&DistanceClip::new(eye, 5.0));
+/// assert_eq!(clipped.len(), 1);
+/// assert!(clipped.iter_paths().flatten().all(|v| v.x <= 5.0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DistanceClip {
+    pub eye: Vector,
+    pub max_distance: f64,
+}
+
+impl DistanceClip {
+    pub fn new(eye: Vector, max_distance: f64) -> Self {
+        Self { eye, max_distance }
+    }
+}
+
+impl Filter for DistanceClip {
+    fn filter(&self, v: Vector) -> Option<Vector> {
+        (v.sub(self.eye).length() <= self.max_distance).then_some(v)
+    }
+}
@@ -3,7 +3,7 @@ use crate::hit::Hit;
 use crate::path::{Paths, recursive_subdivide};
 use crate::ray::Ray;
 use crate::shape::{RenderArgs, Shape};
-use crate::util::radians;
+use crate::util::{marching_squares, radians};
 use crate::vector::Vector;
 use bon::{Builder, bon};
 
@@ -13,21 +13,67 @@ pub enum Direction {
     Below,
 }
 
+const DEFAULT_GRID_SIZE: f64 = 1.0 / 8.0;
+
 /// Texture style for Function shapes
+///
+/// # Example
+///
+/// ```
+/// use larnt::{BBox, Camera, Function, FunctionTexture, Shape, Vector};
+///
+/// // Contours of a paraboloid are circles of radius sqrt(z)
+/// let bx = BBox::new(Vector::new(-2.0, -2.0, 0.0), Vector::new(2.0, 2.0, 2.0));
+/// let paraboloid = Function::builder(|x: f64, y: f64| x * x + y * y, bx)
+///     .texture(FunctionTexture::Contours { levels: 3 })
+///     .build();
+///
+/// let args = Camera::builder(Vector::new(4.0, 3.0, 2.0)).build().render_args();
+/// let paths = paraboloid.paths(&args);
+/// assert_eq!(paths.len(), 3);
+/// for v in paths.iter_paths().flatten() {
+///     assert!((v.x.hypot(v.y) - v.z.sqrt()).abs() < 1e-2);
+/// }
+/// ```
 #[derive(Debug, Clone, Copy)]
 pub enum FunctionTexture {
     /// Grid texture with lines along constant x and y (works with any function)
     Grid(f64),
-    /// Radial swirl texture (best for functions returning negative values like -1/(x²+y²))
+    /// Radial swirl texture (works with any function)
+    ///
+    /// Lines twist by `|z|^1.4` radians, one way where `z` is negative and the
+    /// other way where it is positive, so they stay continuous across `z = 0`.
+    /// Points where the function is not finite are skipped.
+    ///
+    /// ```
+    /// use larnt::{BBox, Camera, Function, FunctionTexture, Shape, Vector};
+    ///
+    /// let bx = BBox::new(Vector::new(-3.0, -3.0, -1.0), Vector::new(3.0, 3.0, 1.0));
+    /// // Changes sign across the domain, and is 0 / 0 = NaN at the origin
+    /// let f = Function::builder(|x: f64, y: f64| x.sin() * y.cos() * x.hypot(y) / x.hypot(y), bx)
+    ///     .texture(FunctionTexture::Swirl)
+    ///     .build();
+    ///
+    /// let args = Camera::builder(Vector::new(4.0, 3.0, 2.0)).build().render_args();
+    /// let paths = f.paths(&args);
+    /// assert!(paths.iter_paths().flatten().all(|v| v.x.is_finite() && v.y.is_finite() && v.z.is_finite()));
+    /// // Lines change direction smoothly where the function changes sign
+    /// for path in paths.iter_paths() {
+    ///     assert!(path.windows(2).all(|w| w[0].distance(w[1]) < 0.1));
+    /// }
+    /// ```
     Swirl,
     /// Spiral path texture (works with any function)
     Spiral,
+    /// Contour lines at `levels` evenly spaced heights strictly between the
+    /// bottom and top of the bounding box (works with any function)
+    Contours { levels: u32 },
 }
 
 #[bon]
 impl FunctionTexture {
     #[builder]
-    fn grid(#[builder(default = 1.0 / 8.0)] grid_size: f64) -> Self {
+    fn grid(#[builder(default = DEFAULT_GRID_SIZE)] grid_size: f64) -> Self {
         FunctionTexture::Grid(grid_size)
     }
 }
@@ -38,6 +84,45 @@ impl Default for FunctionTexture {
     }
 }
 
+/// A height field `z = f(x, y)` clipped to a bounding box.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{BBox, Camera, Function, Shape, Vector};
+///
+/// // A 50 unit wide terrain with a grid line every 5 units
+/// let bx = BBox::new(Vector::new(-25.0, -25.0, -20.0), Vector::new(25.0, 25.0, 10.0));
+/// let terrain = Function::builder(|x: f64, y: f64| x.sin() * y.cos(), bx)
+///     .grid_spacing(5.0)
+///     .build();
+///
+/// let args = Camera::builder(Vector::new(28.0, 28.0, 10.0)).build().render_args();
+/// assert_eq!(terrain.paths(&args).len(), 2 * 11);
+///
+/// // A spacing that would never advance falls back to the default of 1/8
+/// let flat = Function::builder(|_: f64, _: f64| 0.0, bx).grid_spacing(0.0).build();
+/// assert_eq!(flat.paths(&args).len(), 2 * (8 * 50 + 1));
+/// ```
+///
+/// Hits carry the normal of the surface from the slope of the function,
+/// pointing out of the solid side chosen by `direction`:
+///
+/// ```
+/// use larnt::{BBox, Direction, Function, Ray, Shape, Vector};
+///
+/// let bx = BBox::new(Vector::new(-2.0, -2.0, -2.0), Vector::new(2.0, 2.0, 2.0));
+/// let ramp = |x: f64, _: f64| x;
+/// let down = Ray::new(Vector::new(0.5, 0.0, 5.0), Vector::new(0.0, 0.0, -1.0));
+///
+/// let below = Function::builder(ramp, bx).step(0.01).build();
+/// let n = below.intersect(down).normal().unwrap();
+/// assert!(n.sub(Vector::new(-1.0, 0.0, 1.0).normalize()).length() < 1e-6);
+///
+/// let above = Function::builder(ramp, bx).direction(Direction::Above).step(0.01).build();
+/// let n = above.intersect(down).normal().unwrap();
+/// assert!(n.sub(Vector::new(1.0, 0.0, -1.0).normalize()).length() < 1e-6);
+/// ```
 #[derive(Debug, Clone, Builder)]
 pub struct Function<F>
 where
@@ -53,6 +138,16 @@ where
     pub texture: FunctionTexture,
     #[builder(default = 0.1)]
     pub step: f64,
+    /// The distance between grid lines, overriding the spacing of
+    /// [`FunctionTexture::Grid`] so it can follow the size of the domain.
+    ///
+    /// A spacing that is zero, negative or NaN is ignored, as is such a
+    /// spacing of the texture, falling back to the default of 1/8.
+    pub grid_spacing: Option<f64>,
+    /// The number of samples along each swirl line, along the spiral, or per
+    /// side of the contour tracing grid. Defaults to 256 samples per unit of
+    /// radius for the swirl, 10000 for the spiral and 128 for contours.
+    pub texture_resolution: Option<usize>,
 }
 
 impl<F> Shape for Function<F>
@@ -92,7 +187,7 @@ where
             t += self.step;
             let v = ray.position(t);
             if self.contains(v, 0.0) != sign && self.bx.contains(v) {
-                return Hit::new(t);
+                return Hit::new(t).with_normal(self.normal(v.x, v.y));
             }
         }
         Hit::no_hit()
@@ -100,17 +195,43 @@ where
 
     fn paths(&self, args: &RenderArgs) -> Paths<Vector> {
         match self.texture {
-            FunctionTexture::Grid(grid_size) => self.paths_grid(args, grid_size),
+            FunctionTexture::Grid(grid_size) => {
+                // Grid lines never advance by a spacing that is not positive
+                let spacing = [self.grid_spacing, Some(grid_size)]
+                    .into_iter()
+                    .flatten()
+                    .find(|&spacing| spacing > 0.0)
+                    .unwrap_or(DEFAULT_GRID_SIZE);
+                self.paths_grid(args, spacing)
+            }
             FunctionTexture::Swirl => self.paths_swirl(),
             FunctionTexture::Spiral => self.paths_spiral(),
+            FunctionTexture::Contours { levels } => self.paths_contours(levels),
         }
     }
+
+    fn view_dependent(&self) -> bool {
+        matches!(self.texture, FunctionTexture::Grid(_))
+    }
 }
 
 impl<F> Function<F>
 where
     F: Fn(f64, f64) -> f64 + Send + Sync,
 {
+    /// Returns the normal of the surface above `(x, y)`, pointing out of the
+    /// solid, from the gradient of the function by central differences.
+    fn normal(&self, x: f64, y: f64) -> Vector {
+        const H: f64 = 1e-6;
+        let dx = ((self.func)(x + H, y) - (self.func)(x - H, y)) / (2.0 * H);
+        let dy = ((self.func)(x, y + H) - (self.func)(x, y - H)) / (2.0 * H);
+        let up = Vector::new(-dx, -dy, 1.0);
+        match self.direction {
+            Direction::Below => up,
+            Direction::Above => up.mul_scalar(-1.0),
+        }
+    }
+
     /// Calculate max radius for radial textures based on bbox dimensions
     fn max_radius(&self) -> f64 {
         (self.bx.max.x - self.bx.min.x).max(self.bx.max.y - self.bx.min.y) / 2.0
@@ -139,7 +260,9 @@ where
                 &|(a, fa), (b, fb)| {
                     let sa = args.screen_mat.mul_position_w(Vector::new(x, a, fa));
                     let sb = args.screen_mat.mul_position_w(Vector::new(x, b, fb));
-                    sa.distance_squared(sb) < step_sq || (a - b).powi(2) < crate::common::EPS
+                    sa.distance_squared(sb) < step_sq
+                        || (a - b).powi(2) < crate::common::EPS
+                        || !sa.distance_squared(sb).is_finite()
                 },
                 &mut |(y, fy)| path.push(Vector::new(x, y, fy)),
             );
@@ -164,7 +287,9 @@ where
                 &|(a, fa), (b, fb)| {
                     let sa = args.screen_mat.mul_position_w(Vector::new(a, y, fa));
                     let sb = args.screen_mat.mul_position_w(Vector::new(b, y, fb));
-                    sa.distance_squared(sb) < step_sq || (a - b).powi(2) < crate::common::EPS
+                    sa.distance_squared(sb) < step_sq
+                        || (a - b).powi(2) < crate::common::EPS
+                        || !sa.distance_squared(sb).is_finite()
                 },
                 &mut |(x, fx)| path.push(Vector::new(x, y, fx)),
             );
@@ -175,11 +300,13 @@ where
         paths
     }
 
-    /// Swirl texture - radial lines with twist effect (best for negative z functions)
+    /// Swirl texture - radial lines with twist effect
     fn paths_swirl(&self) -> Paths<Vector> {
         let mut paths = Paths::new();
-        let fine = 1.0 / 256.0;
         let max_radius = self.max_radius();
+        let fine = self
+            .texture_resolution
+            .map_or(1.0 / 256.0, |n| max_radius / n.max(1) as f64);
 
         let mut a = 0;
         while a < 360 {
@@ -189,14 +316,15 @@ where
                 let x = radians(a as f64).cos() * r;
                 let y = radians(a as f64).sin() * r;
                 let mut z = (self.func)(x, y);
-                // Only apply swirl effect when z is negative to avoid NaN
-                let o = if z < 0.0 { -(-z).powf(1.4) } else { 0.0 };
+                // Odd in z, so the twist is continuous across z = 0
+                let o = z.signum() * z.abs().powf(1.4);
                 let x = (radians(a as f64) - o).cos() * r;
                 let y = (radians(a as f64) - o).sin() * r;
                 z = z.min(self.bx.max.z).max(self.bx.min.z);
 
-                // Check if point is within bbox x/y bounds
-                if x >= self.bx.min.x
+                // Check if point is finite and within bbox x/y bounds
+                if o.is_finite()
+                    && x >= self.bx.min.x
                     && x <= self.bx.max.x
                     && y >= self.bx.min.y
                     && y <= self.bx.max.y
@@ -219,7 +347,7 @@ where
     fn paths_spiral(&self) -> Paths<Vector> {
         let mut paths = Paths::new();
         let mut path = paths.new_path();
-        let n = 10000;
+        let n = self.texture_resolution.unwrap_or(10000);
         let max_radius = self.max_radius();
 
         for i in 0..n {
@@ -243,6 +371,38 @@ where
         drop(path);
         paths
     }
+
+    fn paths_contours(&self, levels: u32) -> Paths<Vector> {
+        let mut paths = Paths::new();
+        let n = self.texture_resolution.unwrap_or(128).max(1);
+        let (min, max) = (self.bx.min, self.bx.max);
+        let position = |i: usize, j: usize| {
+            (
+                min.x + (max.x - min.x) * i as f64 / n as f64,
+                min.y + (max.y - min.y) * j as f64 / n as f64,
+            )
+        };
+        let values: Vec<f64> = (0..=n)
+            .flat_map(|i| (0..=n).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                let (x, y) = position(i, j);
+                (self.func)(x, y)
+            })
+            .collect();
+
+        for k in 1..=levels {
+            let z = min.z + (max.z - min.z) * k as f64 / (levels + 1) as f64;
+            let value = |i: usize, j: usize| values[i * (n + 1) + j] - z;
+            paths.extend(marching_squares(n, value).map(|(i, j, dir)| {
+                let (i2, j2) = if dir == 0 { (i + 1, j) } else { (i, j + 1) };
+                let (v1, v2) = (value(i, j), value(i2, j2));
+                let t = v1 / (v1 - v2);
+                let ((x1, y1), (x2, y2)) = (position(i, j), position(i2, j2));
+                Vector::new(x1 + (x2 - x1) * t, y1 + (y2 - y1) * t, z)
+            }));
+        }
+        paths
+    }
 }
 
 pub fn zvisible_offset(path: &mut [Vector], eye: Vector) {
@@ -0,0 +1,132 @@
+//! Ground grid primitive.
+//!
+//! This module provides the [`Grid`] shape, a finite horizontal plane drawn as
+//! grid lines. It participates in occlusion, hiding geometry below it.
+//!
+//! # Example
+//!
+//! ```
+//! use larnt::{Grid, Sphere, Vector, render};
+//! # use larnt::Primitive;
+//!
+//! // A 4x4 floor at z=-1 with lines every 0.5 units
+//! let grid = Grid::builder(-1.0, Vector::new(-2.0, -2.0, 0.0), Vector::new(2.0, 2.0, 0.0))
+//!     .x_spacing(0.5)
+//!     .y_spacing(0.5)
+//!     .build();
+//! let sphere = Sphere::builder(Vector::new(0.0, 0.0, 0.0), 1.0).build();
+//!
+//! let paths = render::<Primitive>(vec![grid.into(), sphere.into()])
+//!     .eye(Vector::new(4.0, 3.0, 2.0))
+//!     .call();
+//! ```
+
+use crate::bounding_box::BBox;
+use crate::common::EPS;
+use crate::hit::Hit;
+use crate::path::Paths;
+use crate::ray::Ray;
+use crate::shape::{RenderArgs, Shape};
+use crate::vector::Vector;
+use bon::Builder;
+
+/// A finite grid lying in the horizontal plane `z`.
+///
+/// The grid covers the rectangle from `min` to `max` in X and Y (the Z
+/// components are ignored), with lines every `x_spacing` along X and every
+/// `y_spacing` along Y. The border is always drawn.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Grid, Ray, Shape, Vector};
+///
+/// let grid = Grid::builder(0.0, Vector::new(-1.0, -1.0, 0.0), Vector::new(1.0, 1.0, 0.0)).build();
+///
+/// let down = Vector::new(0.0, 0.0, -1.0);
+/// assert!(grid.intersect(Ray::new(Vector::new(0.5, 0.5, 1.0), down)).is_ok());
+/// assert!(!grid.intersect(Ray::new(Vector::new(1.5, 0.5, 1.0), down)).is_ok());
+/// ```
+#[derive(Debug, Clone, Builder)]
+pub struct Grid {
+    /// The height of the plane.
+    #[builder(start_fn)]
+    pub z: f64,
+    /// The minimum X and Y coordinates.
+    #[builder(start_fn)]
+    pub min: Vector,
+    /// The maximum X and Y coordinates.
+    #[builder(start_fn)]
+    pub max: Vector,
+    /// The distance between lines parallel to the Y axis.
+    #[builder(default = 1.0)]
+    pub x_spacing: f64,
+    /// The distance between lines parallel to the X axis.
+    #[builder(default = 1.0)]
+    pub y_spacing: f64,
+}
+
+/// Returns the coordinates from `min` to `max` every `spacing`, always including `max`.
+fn grid_lines(min: f64, max: f64, spacing: f64) -> impl Iterator<Item = f64> {
+    let n = if spacing > 0.0 {
+        ((max - min) / spacing - EPS).ceil().max(0.0) as usize
+    } else {
+        0
+    };
+    (0..n)
+        .map(move |i| min + i as f64 * spacing)
+        .chain(std::iter::once(max))
+}
+
+impl Shape for Grid {
+    fn bounding_box(&self) -> BBox {
+        BBox::new(
+            Vector::new(self.min.x, self.min.y, self.z),
+            Vector::new(self.max.x, self.max.y, self.z),
+        )
+    }
+
+    fn contains(&self, _v: Vector, _f: f64) -> bool {
+        false
+    }
+
+    fn intersect(&self, r: Ray) -> Hit {
+        let d = r.direction.z;
+        if d > -EPS && d < EPS {
+            return Hit::no_hit();
+        }
+
+        let t = (self.z - r.origin.z) / d;
+        if t < EPS {
+            return Hit::no_hit();
+        }
+
+        let p = r.position(t);
+        if p.x < self.min.x || p.x > self.max.x || p.y < self.min.y || p.y > self.max.y {
+            return Hit::no_hit();
+        }
+
+        Hit::new(t).with_normal(Vector::new(0.0, 0.0, 1.0))
+    }
+
+    fn paths(&self, _args: &RenderArgs) -> Paths<Vector> {
+        let mut paths = Paths::new();
+        let (min, max, z) = (self.min, self.max, self.z);
+
+        for x in grid_lines(min.x, max.x, self.x_spacing) {
+            paths
+                .new_path()
+                .extend([Vector::new(x, min.y, z), Vector::new(x, max.y, z)]);
+        }
+        for y in grid_lines(min.y, max.y, self.y_spacing) {
+            paths
+                .new_path()
+                .extend([Vector::new(min.x, y, z), Vector::new(max.x, y, z)]);
+        }
+        paths
+    }
+
+    fn view_dependent(&self) -> bool {
+        false
+    }
+}
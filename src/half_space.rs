@@ -0,0 +1,105 @@
+//! Half-space primitive.
+//!
+//! This module provides the [`HalfSpace`] shape, everything on one side of an
+//! infinite plane. It draws nothing by itself and is meant as a CSG operand,
+//! e.g. to cut away part of a solid for a cross-section view.
+//!
+//! # Example
+//!
+//! ```
+//! use larnt::{HalfSpace, Primitive, Sphere, Vector, new_intersection, render};
+//!
+//! // The lower half of a sphere
+//! let sphere: Primitive = Sphere::builder(Vector::new(0.0, 0.0, 0.0), 1.0).build().into();
+//! let below: Primitive = HalfSpace::new(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0)).into();
+//! let cut = new_intersection(vec![sphere, below]);
+//!
+//! let paths = render(vec![cut]).eye(Vector::new(4.0, 3.0, 2.0)).call();
+//! ```
+
+use crate::bounding_box::BBox;
+use crate::common::{EPS, INF};
+use crate::hit::Hit;
+use crate::path::Paths;
+use crate::ray::Ray;
+use crate::shape::{RenderArgs, Shape};
+use crate::vector::Vector;
+
+/// The half of space behind a plane.
+///
+/// A point is inside when it lies on the side opposite to `normal`, so the
+/// normal points out of the solid like a surface normal.
+///
+/// The bounding box is effectively infinite, spanning `±INF` on every axis.
+/// Inside [`new_intersection`] this is harmless, the result is bounded by the
+/// other operands. Rendered on its own, it hides everything behind the plane
+/// and makes [`Scene::bounding_box`] unbounded.
+///
+/// [`new_intersection`]: crate::new_intersection
+/// [`Scene::bounding_box`]: crate::Scene::bounding_box
+///
+/// # Example
+///
+/// ```
+/// use larnt::{HalfSpace, Ray, Shape, Vector};
+///
+/// let half = HalfSpace::new(Vector::new(0.0, 0.0, 1.0), Vector::new(0.0, 0.0, 2.0));
+/// assert!(half.contains(Vector::new(5.0, -3.0, 0.5), 0.0));
+/// assert!(!half.contains(Vector::new(5.0, -3.0, 1.5), 0.0));
+///
+/// let hit = half.intersect(Ray::new(Vector::new(0.0, 0.0, 4.0), Vector::new(0.0, 0.0, -1.0)));
+/// assert!((hit.t - 3.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct HalfSpace {
+    /// A point on the boundary plane.
+    pub point: Vector,
+    /// The unit normal of the plane, pointing away from the inside.
+    pub normal: Vector,
+}
+
+impl HalfSpace {
+    /// Creates the half-space behind the plane through `point` with `normal`.
+    pub fn new(point: Vector, normal: Vector) -> Self {
+        HalfSpace {
+            point,
+            normal: normal.normalize(),
+        }
+    }
+
+    /// Returns the signed distance of `v` to the plane, negative inside.
+    pub fn signed_distance(&self, v: Vector) -> f64 {
+        v.sub(self.point).dot(self.normal)
+    }
+}
+
+impl Shape for HalfSpace {
+    fn bounding_box(&self) -> BBox {
+        BBox::new(Vector::new(-INF, -INF, -INF), Vector::new(INF, INF, INF))
+    }
+
+    fn contains(&self, v: Vector, f: f64) -> bool {
+        self.signed_distance(v) <= f
+    }
+
+    fn intersect(&self, r: Ray) -> Hit {
+        let d = self.normal.dot(r.direction);
+        if d.abs() < EPS {
+            return Hit::no_hit();
+        }
+        let t = -self.signed_distance(r.origin) / d;
+        if t > EPS && t < INF {
+            Hit::new(t).with_normal(self.normal)
+        } else {
+            Hit::no_hit()
+        }
+    }
+
+    fn paths(&self, _args: &RenderArgs) -> Paths<Vector> {
+        Paths::new()
+    }
+
+    fn view_dependent(&self) -> bool {
+        false
+    }
+}
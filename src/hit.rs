@@ -1,18 +1,90 @@
 use crate::common::INF;
+use crate::ray::Ray;
+use crate::vector::Vector;
 
+/// The result of intersecting a ray with a shape.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Ray, Shape, Sphere, Vector};
+///
+/// let sphere = Sphere::builder(Vector::new(0.0, 0.0, 0.0), 1.0).build();
+/// let ray = Ray::new(Vector::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, -1.0));
+///
+/// let hit = sphere.intersect(ray);
+/// assert_eq!(hit.t, 4.0);
+/// assert_eq!(hit.point(ray), Vector::new(0.0, 0.0, 1.0));
+/// assert_eq!(hit.normal(), Some(Vector::new(0.0, 0.0, 1.0)));
+/// ```
+///
+/// Normals follow transformed shapes and point out of CSG results:
+///
+/// ```
+/// use larnt::{Cube, Matrix, Primitive, Ray, Shape, Sphere, TransformedShape, Vector, new_difference, radians};
+///
+/// let cube = || -> Primitive { Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build().into() };
+/// let turned = TransformedShape::new(cube(), Matrix::rotate(Vector::new(0.0, 0.0, 1.0), radians(90.0)));
+/// let n = turned.intersect(Ray::new(Vector::new(5.0, 0.0, 0.0), Vector::new(-1.0, 0.0, 0.0))).normal().unwrap();
+/// assert!(n.sub(Vector::new(1.0, 0.0, 0.0)).length() < 1e-9);
+///
+/// // Looking into a spherical dent in the top of the cube
+/// let dented = new_difference(vec![cube(), Sphere::builder(Vector::new(0.0, 0.0, 1.5), 1.0).build().into()]);
+/// let n = dented.intersect(Ray::new(Vector::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, -1.0))).normal().unwrap();
+/// assert!(n.sub(Vector::new(0.0, 0.0, 1.0)).length() < 1e-9);
+/// ```
+///
+/// Fields may be added in later versions, so hits are built with
+/// [`Hit::new`], [`Hit::no_hit`] and [`Hit::with_normal`] rather than struct
+/// literals.
 #[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
 pub struct Hit {
     pub t: f64,
     pub ok: bool,
+    /// The unit normal of the surface at the hit, pointing out of solids, if
+    /// the shape provides it.
+    pub normal: Option<Vector>,
 }
 
 impl Hit {
     pub fn new(t: f64) -> Self {
-        Hit { t, ok: true }
+        Hit {
+            t,
+            ok: true,
+            normal: None,
+        }
     }
 
     pub fn no_hit() -> Self {
-        Hit { t: INF, ok: false }
+        Hit {
+            t: INF,
+            ok: false,
+            normal: None,
+        }
+    }
+
+    /// Returns the hit with the surface normal `normal`, which is normalized.
+    pub fn with_normal(self, normal: Vector) -> Self {
+        Hit {
+            normal: Some(normal.normalize()),
+            ..self
+        }
+    }
+
+    /// Returns the unit surface normal at the hit, if known.
+    ///
+    /// Solids give the outward normal regardless of the side the ray comes
+    /// from, open surfaces like triangles and disks their front side. Shapes
+    /// that do not compute normals give `None`.
+    pub fn normal(&self) -> Option<Vector> {
+        self.normal
+    }
+
+    /// Returns the point where `ray`, the ray that produced this hit, meets
+    /// the surface.
+    pub fn point(&self, ray: Ray) -> Vector {
+        ray.position(self.t)
     }
 
     pub fn is_ok(&self) -> bool {
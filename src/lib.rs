@@ -1,14 +1,22 @@
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "gif")]
+pub mod anim;
 pub mod axis;
 pub mod bounding_box;
+pub mod box_outline;
+pub mod capsule;
 pub mod common;
 pub mod cone;
 pub mod csg;
 pub mod cube;
 pub mod cylinder;
+pub mod disk;
+pub mod ellipsoid;
 pub mod filter;
 pub mod function;
+pub mod grid;
+pub mod half_space;
 pub mod hit;
 pub mod matrix;
 pub mod mesh;
@@ -16,51 +24,91 @@ pub mod obj;
 pub mod parametric;
 pub mod path;
 pub mod plane;
+pub mod ply;
+pub mod point_cloud;
+pub mod quadric;
+pub mod quaternion;
 pub mod ray;
 pub mod scene;
+pub mod segments;
 pub mod shape;
 pub mod sphere;
 pub mod stl;
+pub mod text;
+pub mod torus;
 pub mod tree;
 pub mod triangle;
+pub mod tube;
 pub mod util;
 pub mod vector;
 
 pub use axis::Axis;
 pub use bounding_box::BBox;
-pub use cone::{Cone, ConeTexture, new_transformed_cone};
-pub use csg::{BooleanShape, Op, new_difference, new_intersection};
-pub use cube::{Cube, CubeTexture};
+pub use box_outline::BoxOutline;
+pub use capsule::{Capsule, new_transformed_capsule};
+pub use cone::{
+    Cone, ConeTexture, TruncatedCone, new_transformed_cone, new_transformed_truncated_cone,
+};
+pub use csg::{BooleanShape, Op, new_difference, new_intersection, new_union};
+pub use cube::{Cube, CubeTexture, CubeTextureFn};
 pub use cylinder::{Cylinder, CylinderTexture, new_transformed_cylinder};
-pub use filter::{ClipFilter, Filter};
+pub use disk::Disk;
+pub use ellipsoid::{Ellipsoid, EllipsoidTexture};
+pub use filter::{AndFilter, BoxClip, ClipFilter, DistanceClip, Filter, OrFilter, PlaneClip};
 pub use function::{Direction, Function, FunctionTexture};
+pub use grid::Grid;
+pub use half_space::HalfSpace;
 pub use hit::Hit;
 pub use matrix::Matrix;
 pub use mesh::{Mesh, MeshTexture};
 pub use obj::load_obj;
 pub use parametric::ParametricSurface;
-pub use path::{NewPath, Paths};
+pub use path::{ColoredPaths, LineCap, LineJoin, NewPath, Paths, SvgStyle};
 pub use plane::Plane;
+pub use ply::load_ply;
+pub use point_cloud::{PointCloud, PointMarker};
+pub use quadric::{Quadric, QuadricTexture};
+pub use quaternion::Quaternion;
 pub use ray::Ray;
-pub use scene::render;
-pub use shape::{EmptyShape, RenderArgs, Shape, TransformedShape};
+#[cfg(feature = "rayon")]
+pub use scene::par_render;
+pub use scene::{Camera, Projection, Scene, Shading, render};
+pub use segments::Segments;
+pub use shape::{EmptyShape, RenderArgs, Shape, TransformedShape, Wireframe};
 pub use sphere::{Sphere, SphereTexture, lat_lng_to_xyz};
 pub use stl::{load_binary_stl, load_stl, save_binary_stl};
+pub use text::Text;
+pub use torus::{Torus, TorusTexture};
 pub use tree::Tree;
 pub use triangle::Triangle;
+pub use tube::Tube;
 pub use util::{degrees, median, radians};
 pub use vector::Vector;
 
 pub enum Primitive {
     EmptyShape(EmptyShape),
     Cone(Cone),
+    TruncatedCone(TruncatedCone),
     Cube(Cube),
     Cylinder(Cylinder),
+    Capsule(Capsule),
     Sphere(Sphere),
+    Ellipsoid(Ellipsoid),
+    Torus(Torus),
     Triangle(Box<Triangle>),
+    Disk(Disk),
+    Grid(Grid),
+    HalfSpace(HalfSpace),
+    BoxOutline(BoxOutline),
     Mesh(Box<Mesh>),
+    Tube(Box<Tube>),
     ParametricSurface(Box<ParametricSurface>),
+    Quadric(Box<Quadric>),
+    Text(Box<Text>),
+    PointCloud(Box<PointCloud>),
+    Segments(Box<Segments>),
     TransformedShape(Box<TransformedShape<Self>>),
+    Wireframe(Box<Wireframe<Self>>),
     BooleanShape(BooleanShape<Self>),
     Dynamic(Box<dyn Shape + Send + Sync>),
 }
@@ -68,13 +116,27 @@ pub enum Primitive {
 impl_shape_for_enum!(Primitive {
     EmptyShape,
     Cone,
+    TruncatedCone,
     Cube,
     Cylinder,
+    Capsule,
     Sphere,
+    Ellipsoid,
+    Torus,
     Triangle,
+    Disk,
+    Grid,
+    HalfSpace,
+    BoxOutline,
     Mesh,
+    Tube,
     ParametricSurface,
+    Quadric,
+    Text,
+    PointCloud,
+    Segments,
     TransformedShape,
+    Wireframe,
     BooleanShape,
     Dynamic,
 });
@@ -82,17 +144,37 @@ impl_shape_for_enum!(Primitive {
 impl_from_for_enum!(Primitive {
     EmptyShape,
     Cone,
+    TruncatedCone,
     Cube,
     Cylinder,
+    Capsule,
     Sphere,
+    Ellipsoid,
+    Torus,
     Triangle(Box<Triangle>),
     Triangle(Triangle => Box::new),
+    Disk,
+    Grid,
+    HalfSpace,
+    BoxOutline,
     Mesh(Box<Mesh>),
     Mesh(Mesh => Box::new),
+    Tube(Box<Tube>),
+    Tube(Tube => Box::new),
     ParametricSurface(Box<ParametricSurface>),
     ParametricSurface(ParametricSurface => Box::new),
+    Quadric(Box<Quadric>),
+    Quadric(Quadric => Box::new),
+    Text(Box<Text>),
+    Text(Text => Box::new),
+    PointCloud(Box<PointCloud>),
+    PointCloud(PointCloud => Box::new),
+    Segments(Box<Segments>),
+    Segments(Segments => Box::new),
     TransformedShape(Box<TransformedShape<Self>>),
     TransformedShape(TransformedShape<Self> => Box::new),
+    Wireframe(Box<Wireframe<Self>>),
+    Wireframe(Wireframe<Self> => Box::new),
     BooleanShape(BooleanShape<Self>),
     Dynamic(Box<dyn Shape + Send + Sync>),
 });
@@ -112,6 +194,9 @@ macro_rules! impl_shape_for_enum {
 
             #[inline(always)]
             fn paths(&self, args: &RenderArgs) -> Paths<Vector> { match self { $( $enum_name::$variant(inner) => inner.paths(args), )* } }
+
+            #[inline(always)]
+            fn view_dependent(&self) -> bool { match self { $( $enum_name::$variant(inner) => inner.view_dependent(), )* } }
         }
     };
 }
@@ -18,6 +18,8 @@
 //! let combined = rotation.translated(Vector::new(1.0, 2.0, 3.0));
 //! ```
 
+use crate::common::EPS;
+use crate::quaternion::Quaternion;
 use crate::ray::Ray;
 use crate::vector::Vector;
 
@@ -47,7 +49,30 @@ use crate::vector::Vector;
 /// let transformed = transform.mul_position(point);
 /// assert!((transformed.x - 1.0).abs() < 1e-10);
 /// ```
+///
+/// # Serialization
+///
+/// With the `serde` feature, a matrix is (de)serialized as its 16 elements in
+/// row-major order.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use larnt::{Matrix, Vector};
+///
+/// let m = Matrix::translate(Vector::new(1.0, 2.0, 3.0));
+/// let json = serde_json::to_string(&m).unwrap();
+/// assert_eq!(json, "[1.0,0.0,0.0,1.0,0.0,1.0,0.0,2.0,0.0,0.0,1.0,3.0,0.0,0.0,0.0,1.0]");
+/// let back: Matrix = serde_json::from_str(&json).unwrap();
+/// assert_eq!(<[f64; 16]>::from(back), <[f64; 16]>::from(m));
+/// # }
+/// ```
 #[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "[f64; 16]", into = "[f64; 16]")
+)]
 pub struct Matrix {
     pub x00: f64,
     pub x01: f64,
@@ -283,6 +308,38 @@ impl Matrix {
         m.inverse()
     }
 
+    /// Creates a viewport matrix mapping normalized device coordinates
+    /// (`-1..1`) to screen coordinates (`0..width`, `0..height`).
+    ///
+    /// Together with [`look_at`](Matrix::look_at) and
+    /// [`perspective`](Matrix::perspective) this reproduces the projection used
+    /// by [`render`](crate::render), so arbitrary points can be projected offscreen.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Matrix, Vector};
+    ///
+    /// let (width, height) = (1024.0, 768.0);
+    /// let eye = Vector::new(4.0, 3.0, 2.0);
+    /// let center = Vector::new(0.0, 0.0, 0.0);
+    /// let camera = Matrix::look_at(eye, center, Vector::new(0.0, 0.0, 1.0))
+    ///     .with_perspective(50.0, width / height, 0.1, 1000.0);
+    /// let screen = Matrix::viewport(width, height).mul(&camera);
+    ///
+    /// // The point looked at lands in the middle of the canvas
+    /// let p = screen.mul_position_w(center);
+    /// assert!((p.x - width / 2.0).abs() < 1e-9);
+    /// assert!((p.y - height / 2.0).abs() < 1e-9);
+    /// ```
+    pub fn viewport(width: f64, height: f64) -> Self {
+        Matrix::translate(Vector::new(1.0, 1.0, 0.0)).scaled(Vector::new(
+            width / 2.0,
+            height / 2.0,
+            1.0,
+        ))
+    }
+
     /// Returns a new matrix with a translation applied.
     pub fn translated(&self, v: Vector) -> Matrix {
         Matrix::translate(v).mul(self)
@@ -344,6 +401,39 @@ impl Matrix {
         Vector::new(x, y, z)
     }
 
+    /// Transforms a slice of positions by this matrix.
+    ///
+    /// Equivalent to calling [`mul_position`](Matrix::mul_position) on every
+    /// point, see [`transform_points`](Matrix::transform_points) for an
+    /// in-place version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Matrix, Vector, radians};
+    ///
+    /// let m = Matrix::rotate(Vector::new(1.0, 1.0, 0.0), radians(30.0))
+    ///     .translated(Vector::new(1.0, 2.0, 3.0));
+    /// let points = [Vector::new(1.0, 0.0, 0.0), Vector::new(-2.0, 5.0, 0.5)];
+    ///
+    /// let transformed = m.mul_positions(&points);
+    /// assert_eq!(transformed, points.map(|p| m.mul_position(p)));
+    ///
+    /// let mut in_place = points;
+    /// m.transform_points(&mut in_place);
+    /// assert_eq!(in_place.as_slice(), transformed);
+    /// ```
+    pub fn mul_positions(&self, points: &[Vector]) -> Vec<Vector> {
+        points.iter().map(|&p| self.mul_position(p)).collect()
+    }
+
+    /// Transforms a slice of positions by this matrix in place.
+    pub fn transform_points(&self, points: &mut [Vector]) {
+        for p in points {
+            *p = self.mul_position(*p);
+        }
+    }
+
     /// Transforms a position with perspective divide.
     pub fn mul_position_w(&self, b: Vector) -> Vector {
         let x = self.x00 * b.x + self.x01 * b.y + self.x02 * b.z + self.x03;
@@ -400,6 +490,91 @@ impl Matrix {
         crate::bounding_box::BBox { min, max }
     }
 
+    /// Interpolates between two affine transforms.
+    ///
+    /// Both matrices are split into translation, rotation and per-axis scale
+    /// with [`decompose`](Matrix::decompose). The translations and scales are interpolated
+    /// linearly and the rotations with [`Quaternion::slerp`], then the parts
+    /// are recomposed. `t = 0` gives `self` and `t = 1` gives `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Matrix, Vector, radians};
+    ///
+    /// let axis = Vector::new(1.0, -1.0, 2.0);
+    /// let a = Matrix::translate(Vector::new(0.0, 0.0, 0.0));
+    /// let b = Matrix::translate(Vector::new(4.0, 0.0, 0.0)).mul(&Matrix::rotate(axis, radians(90.0)));
+    ///
+    /// let half = a.slerp(&b, 0.5);
+    /// let expected = Matrix::translate(Vector::new(2.0, 0.0, 0.0)).mul(&Matrix::rotate(axis, radians(45.0)));
+    /// let p = Vector::new(1.0, 2.0, 3.0);
+    /// assert!(half.mul_position(p).all_close(expected.mul_position(p)));
+    /// ```
+    pub fn slerp(&self, other: &Matrix, t: f64) -> Matrix {
+        let (t0, r0, s0) = self.decompose();
+        let (t1, r1, s1) = other.decompose();
+        let (r0, r1) = (Quaternion::from_matrix(&r0), Quaternion::from_matrix(&r1));
+        let translation = t0.add(t1.sub(t0).mul_scalar(t));
+        let scale = s0.add(s1.sub(s0).mul_scalar(t));
+        Matrix::translate(translation)
+            .mul(&r0.slerp(&r1, t).to_matrix())
+            .mul(&Matrix::scale(scale))
+    }
+
+    /// Decomposes an affine transform into translation, rotation and scale.
+    ///
+    /// Returns `(translation, rotation, scale)` such that the matrix equals
+    /// `Matrix::translate(translation).mul(&rotation).mul(&Matrix::scale(scale))`.
+    /// The matrix is assumed to have no shear or projection. A mirroring
+    /// transform is reported as a negative x scale, so that `rotation` is
+    /// always a proper rotation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Matrix, Vector, radians};
+    ///
+    /// let rotation = Matrix::rotate(Vector::new(1.0, 2.0, 3.0), radians(70.0));
+    /// let m = Matrix::translate(Vector::new(1.0, -2.0, 5.0))
+    ///     .mul(&rotation)
+    ///     .mul(&Matrix::scale(Vector::new(2.0, 0.5, 3.0)));
+    ///
+    /// let (t, r, s) = m.decompose();
+    /// assert!(t.all_close(Vector::new(1.0, -2.0, 5.0)));
+    /// assert!(s.all_close(Vector::new(2.0, 0.5, 3.0)));
+    /// let (r, rotation) = (<[f64; 16]>::from(r), <[f64; 16]>::from(rotation));
+    /// assert!(r.iter().zip(rotation).all(|(a, b)| (a - b).abs() < 1e-9));
+    /// ```
+    pub fn decompose(&self) -> (Vector, Matrix, Vector) {
+        let translation = Vector::new(self.x03, self.x13, self.x23);
+        let x = Vector::new(self.x00, self.x10, self.x20);
+        let y = Vector::new(self.x01, self.x11, self.x21);
+        let z = Vector::new(self.x02, self.x12, self.x22);
+        let mut scale = Vector::new(x.length(), y.length(), z.length());
+        if x.cross(y).dot(z) < 0.0 {
+            scale.x = -scale.x;
+        }
+        let (x, y, z) = (
+            x.div_scalar(scale.x),
+            y.div_scalar(scale.y),
+            z.div_scalar(scale.z),
+        );
+        let rotation = Matrix {
+            x00: x.x,
+            x10: x.y,
+            x20: x.z,
+            x01: y.x,
+            x11: y.y,
+            x21: y.z,
+            x02: z.x,
+            x12: z.y,
+            x22: z.z,
+            ..Matrix::identity()
+        };
+        (translation, rotation, scale)
+    }
+
     /// Returns the transpose of this matrix.
     pub fn transpose(&self) -> Matrix {
         Matrix {
@@ -451,10 +626,39 @@ impl Matrix {
     }
 
     /// Computes the inverse of this matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is singular. Use
+    /// [`try_inverse`](Matrix::try_inverse) when the matrix may come from
+    /// untrusted input, such as a zero scale.
     pub fn inverse(&self) -> Matrix {
+        self.try_inverse()
+            .expect("matrix is singular and cannot be inverted")
+    }
+
+    /// Computes the inverse of this matrix, or returns `None` if the matrix is
+    /// singular (its determinant is smaller than [`EPS`](crate::common::EPS) in magnitude).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Matrix, Vector};
+    ///
+    /// let m = Matrix::scale(Vector::new(2.0, 4.0, 8.0));
+    /// let p = Vector::new(1.0, 2.0, 3.0);
+    /// let inverse = m.try_inverse().unwrap();
+    /// assert!(inverse.mul_position(m.mul_position(p)).all_close(p));
+    ///
+    /// assert!(Matrix::scale(Vector::new(0.0, 0.0, 0.0)).try_inverse().is_none());
+    /// ```
+    pub fn try_inverse(&self) -> Option<Matrix> {
         let a = self;
         let d = self.determinant();
-        Matrix {
+        if d.abs() < EPS || !d.is_finite() {
+            return None;
+        }
+        Some(Matrix {
             x00: (a.x12 * a.x23 * a.x31 - a.x13 * a.x22 * a.x31 + a.x13 * a.x21 * a.x32
                 - a.x11 * a.x23 * a.x32
                 - a.x12 * a.x21 * a.x33
@@ -535,6 +739,56 @@ impl Matrix {
                 - a.x01 * a.x10 * a.x22
                 + a.x00 * a.x11 * a.x22)
                 / d,
+        })
+    }
+}
+
+impl From<[f64; 16]> for Matrix {
+    fn from(m: [f64; 16]) -> Self {
+        let [
+            x00,
+            x01,
+            x02,
+            x03,
+            x10,
+            x11,
+            x12,
+            x13,
+            x20,
+            x21,
+            x22,
+            x23,
+            x30,
+            x31,
+            x32,
+            x33,
+        ] = m;
+        Matrix {
+            x00,
+            x01,
+            x02,
+            x03,
+            x10,
+            x11,
+            x12,
+            x13,
+            x20,
+            x21,
+            x22,
+            x23,
+            x30,
+            x31,
+            x32,
+            x33,
         }
     }
 }
+
+impl From<Matrix> for [f64; 16] {
+    fn from(m: Matrix) -> Self {
+        [
+            m.x00, m.x01, m.x02, m.x03, m.x10, m.x11, m.x12, m.x13, m.x20, m.x21, m.x22, m.x23,
+            m.x30, m.x31, m.x32, m.x33,
+        ]
+    }
+}
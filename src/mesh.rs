@@ -15,14 +15,47 @@ pub enum MeshTexture {
     #[default]
     Triangles,
     Polygonal,
+    /// Silhouette edges, where one adjacent face points toward the eye and
+    /// the other away, plus boundary edges. Edges whose adjacent faces meet
+    /// with `|cos θ|` at most the given value are kept as creases, so `0.0`
+    /// draws the pure silhouette.
     Silhouette(f64),
+    /// Boundary edges plus edges whose dihedral angle exceeds the given
+    /// number of degrees.
+    Crease(f64),
 }
 
 #[bon]
 impl MeshTexture {
+    /// Builds a [`MeshTexture::Silhouette`], the crease threshold can be given
+    /// either as `cos_theta` or as `crease_angle` in degrees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Camera, Mesh, MeshTexture, Shape, Vector};
+    ///
+    /// let args = Camera::builder(Vector::new(40.0, 30.0, 20.0)).build().render_args();
+    /// let segments = |texture| {
+    ///     let mut mesh = Mesh::icosahedron(1.0);
+    ///     mesh.texture = texture;
+    ///     mesh.paths(&args).iter_paths().map(|p| p.len() - 1).sum::<usize>()
+    /// };
+    ///
+    /// // The outline of a convex mesh is a single loop of a few edges
+    /// let outline = segments(MeshTexture::silhouette().call());
+    /// assert!(outline > 0 && outline < 30);
+    /// // Every dihedral angle of an icosahedron exceeds 30 degrees
+    /// assert_eq!(segments(MeshTexture::silhouette().crease_angle(30.0).call()), 30);
+    /// ```
     #[builder]
-    pub fn silhouette(#[builder(default = 0.0)] cos_theta: f64) -> Self {
-        Self::Silhouette(cos_theta)
+    pub fn silhouette(#[builder(default = 0.0)] cos_theta: f64, crease_angle: Option<f64>) -> Self {
+        Self::Silhouette(crease_angle.map_or(cos_theta, |degrees| degrees.to_radians().cos()))
+    }
+
+    #[builder]
+    pub fn crease(#[builder(default = 30.0)] degrees: f64) -> Self {
+        Self::Crease(degrees)
     }
 }
 
@@ -58,6 +91,526 @@ impl Mesh {
         Self::builder(merger.vertices, itriangles).build()
     }
 
+    /// Regular tetrahedron centered at the origin with the given circumradius.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::Mesh;
+    ///
+    /// let mesh = Mesh::tetrahedron(1.0);
+    /// assert_eq!(mesh.vertices.len(), 4);
+    /// assert_eq!(mesh.triangles.len() / 3, 4);
+    /// ```
+    pub fn tetrahedron(radius: f64) -> Self {
+        let vertices = [(1, 1, 1), (1, -1, -1), (-1, 1, -1), (-1, -1, 1)]
+            .map(|(x, y, z)| Vector::new(x as f64, y as f64, z as f64));
+        let faces = [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]];
+        Self::regular_polyhedron(&vertices, &faces, radius)
+    }
+
+    /// Regular octahedron centered at the origin with the given circumradius.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::Mesh;
+    ///
+    /// let mesh = Mesh::octahedron(1.0);
+    /// assert_eq!(mesh.vertices.len(), 6);
+    /// assert_eq!(mesh.triangles.len() / 3, 8);
+    /// ```
+    pub fn octahedron(radius: f64) -> Self {
+        let vertices = [
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(-1.0, 0.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+            Vector::new(0.0, -1.0, 0.0),
+            Vector::new(0.0, 0.0, 1.0),
+            Vector::new(0.0, 0.0, -1.0),
+        ];
+        let faces = [
+            [0, 2, 4],
+            [2, 1, 4],
+            [1, 3, 4],
+            [3, 0, 4],
+            [2, 0, 5],
+            [1, 2, 5],
+            [3, 1, 5],
+            [0, 3, 5],
+        ];
+        Self::regular_polyhedron(&vertices, &faces, radius)
+    }
+
+    /// Regular icosahedron centered at the origin with the given circumradius.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Mesh, Vector};
+    ///
+    /// let mesh = Mesh::icosahedron(2.0);
+    /// assert_eq!(mesh.vertices.len(), 12);
+    /// assert_eq!(mesh.triangles.len() / 3, 20);
+    /// assert!(mesh.vertices.iter().all(|v| (v.length() - 2.0).abs() < 1e-9));
+    ///
+    /// // Every edge is shared by exactly two faces
+    /// assert_eq!(mesh.filter_paths(|edges| edges.len() == 2).len(), 30);
+    /// ```
+    pub fn icosahedron(radius: f64) -> Self {
+        let p = (1.0 + 5.0_f64.sqrt()) / 2.0;
+        let vertices = [
+            Vector::new(-1.0, p, 0.0),
+            Vector::new(1.0, p, 0.0),
+            Vector::new(-1.0, -p, 0.0),
+            Vector::new(1.0, -p, 0.0),
+            Vector::new(0.0, -1.0, p),
+            Vector::new(0.0, 1.0, p),
+            Vector::new(0.0, -1.0, -p),
+            Vector::new(0.0, 1.0, -p),
+            Vector::new(p, 0.0, -1.0),
+            Vector::new(p, 0.0, 1.0),
+            Vector::new(-p, 0.0, -1.0),
+            Vector::new(-p, 0.0, 1.0),
+        ];
+        let faces = [
+            [0, 11, 5],
+            [0, 5, 1],
+            [0, 1, 7],
+            [0, 7, 10],
+            [0, 10, 11],
+            [1, 5, 9],
+            [5, 11, 4],
+            [11, 10, 2],
+            [10, 7, 6],
+            [7, 1, 8],
+            [3, 9, 4],
+            [3, 4, 2],
+            [3, 2, 6],
+            [3, 6, 8],
+            [3, 8, 9],
+            [4, 9, 5],
+            [2, 4, 11],
+            [6, 2, 10],
+            [8, 6, 7],
+            [9, 8, 1],
+        ];
+        Self::regular_polyhedron(&vertices, &faces, radius)
+    }
+
+    /// Closed terrain block from a grid of elevation samples.
+    ///
+    /// `data` holds `width * height` samples row by row, the first row along
+    /// the `bx.min.y` edge and each row running from `bx.min.x` to `bx.max.x`.
+    /// The samples are scaled so the lowest one lies at `bx.min.z` and the
+    /// highest at `bx.max.z`. Each grid cell becomes two triangles of the top
+    /// surface, which is closed with walls down to a flat bottom at `bx.min.z`
+    /// triangulated around its center.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is less than 2, or if `data` does not
+    /// hold `width * height` samples.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{BBox, Mesh, Shape, Vector};
+    ///
+    /// let (width, height) = (20, 10);
+    /// let data: Vec<f64> = (0..width * height)
+    ///     .map(|k| ((k % width) as f64 * 0.3).sin() + ((k / width) as f64 * 0.5).cos())
+    ///     .collect();
+    /// let bx = BBox::new(Vector::new(0.0, 0.0, 0.0), Vector::new(4.0, 2.0, 1.0));
+    /// let mesh = Mesh::from_heightmap(&data, width, height, bx);
+    ///
+    /// // The grid on top plus the perimeter and center of the bottom
+    /// assert_eq!(mesh.vertices.len(), width * height + 2 * (width + height) - 4 + 1);
+    /// assert!(mesh.bounding_box().min.distance(bx.min) < 1e-9);
+    /// assert!(mesh.bounding_box().max.distance(bx.max) < 1e-9);
+    /// // Watertight: every edge is shared by exactly two faces
+    /// assert_eq!(mesh.filter_paths(|edges| edges.len() != 2).len(), 0);
+    /// ```
+    pub fn from_heightmap(data: &[f64], width: usize, height: usize, bx: BBox) -> Self {
+        assert!(
+            width >= 2 && height >= 2,
+            "heightmap must be at least 2x2, got {width}x{height}"
+        );
+        assert_eq!(
+            data.len(),
+            width * height,
+            "heightmap data must hold width * height samples"
+        );
+
+        let (lo, hi) = data
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| {
+                (lo.min(v), hi.max(v))
+            });
+        let size = bx.size();
+        let position = |i: usize, j: usize| {
+            let x = bx.min.x + size.x * i as f64 / (width - 1) as f64;
+            let y = bx.min.y + size.y * j as f64 / (height - 1) as f64;
+            (x, y)
+        };
+        let mut vertices: Vec<Vector> = (0..width * height)
+            .map(|k| {
+                let (x, y) = position(k % width, k / width);
+                let t = if hi > lo {
+                    (data[k] - lo) / (hi - lo)
+                } else {
+                    1.0
+                };
+                Vector::new(x, y, bx.min.z + size.z * t)
+            })
+            .collect();
+
+        let mut triangles = Vec::with_capacity(6 * (width * height + 2 * (width + height)));
+        for j in 0..height - 1 {
+            for i in 0..width - 1 {
+                let [a, b, c, d] =
+                    [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)].map(|(i, j)| j * width + i);
+                triangles.extend([a, b, c, a, c, d]);
+            }
+        }
+
+        // The border of the grid, counterclockwise seen from above
+        let border: Vec<(usize, usize)> = (0..width - 1)
+            .map(|i| (i, 0))
+            .chain((0..height - 1).map(|j| (width - 1, j)))
+            .chain((1..width).rev().map(|i| (i, height - 1)))
+            .chain((1..height).rev().map(|j| (0, j)))
+            .collect();
+        let top = |k: usize| border[k].1 * width + border[k].0;
+        let bottom = |k: usize| width * height + k;
+        vertices.extend(border.iter().map(|&(i, j)| {
+            let (x, y) = position(i, j);
+            Vector::new(x, y, bx.min.z)
+        }));
+
+        let n = border.len();
+        for k in 0..n {
+            let next = (k + 1) % n;
+            triangles.extend([bottom(k), bottom(next), top(next)]);
+            triangles.extend([bottom(k), top(next), top(k)]);
+        }
+        // Fan around the center of the bottom, wound to face down
+        let center = vertices.len();
+        vertices.push(Vector::new(bx.center().x, bx.center().y, bx.min.z));
+        for k in 0..n {
+            triangles.extend([center, bottom((k + 1) % n), bottom(k)]);
+        }
+
+        Self::builder(vertices, triangles).build()
+    }
+
+    /// Isosurface `f(v) = 0` of a scalar field, e.g. a signed distance function.
+    ///
+    /// `f` is sampled on a grid of `resolution` cells along each axis of `bx`.
+    /// Points where `f` is negative are inside, and the faces point outwards.
+    /// Each cell is split into six tetrahedra (marching tetrahedra), which
+    /// needs no lookup tables and has no ambiguous cases, so the surface is
+    /// closed wherever it does not leave `bx`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{BBox, Mesh, Vector};
+    ///
+    /// let bx = BBox::new(Vector::new(-1.5, -1.5, -1.5), Vector::new(1.5, 1.5, 1.5));
+    /// let mesh = Mesh::from_sdf(|v| v.length() - 1.0, bx, 20);
+    ///
+    /// // Vertices lie on the unit sphere up to the grid spacing
+    /// assert!(mesh.vertices.iter().all(|v| (v.length() - 1.0).abs() < 0.15));
+    /// // Watertight: every edge is shared by exactly two faces
+    /// assert!(mesh.triangles.len() > 0);
+    /// assert_eq!(mesh.filter_paths(|edges| edges.len() != 2).len(), 0);
+    /// ```
+    pub fn from_sdf(f: impl Fn(Vector) -> f64, bx: BBox, resolution: usize) -> Self {
+        let n = resolution.max(1);
+        let size = bx.size();
+        let index = |i: usize, j: usize, k: usize| (k * (n + 1) + j) * (n + 1) + i;
+        let point = |g: usize| {
+            let (i, j, k) = (g % (n + 1), g / (n + 1) % (n + 1), g / (n + 1) / (n + 1));
+            let t = Vector::new(i as f64, j as f64, k as f64).div_scalar(n as f64);
+            bx.min.add(size.mul(t))
+        };
+        let values: Vec<f64> = (0..(n + 1).pow(3)).map(|g| f(point(g))).collect();
+
+        let mut vertices = Vec::new();
+        let mut triangles = Vec::new();
+        // One vertex per grid edge crossing the surface, shared by all its faces
+        let mut crossings: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut crossing = |a: usize, b: usize, vertices: &mut Vec<Vector>| {
+            *crossings.entry((a.min(b), a.max(b))).or_insert_with(|| {
+                let t = values[a] / (values[a] - values[b]);
+                vertices.push(point(a).add(point(b).sub(point(a)).mul_scalar(t)));
+                vertices.len() - 1
+            })
+        };
+
+        for k in 0..n {
+            for j in 0..n {
+                for i in 0..n {
+                    let corner = |c: usize| index(i + (c & 1), j + (c >> 1 & 1), k + (c >> 2));
+                    // Six tetrahedra around the diagonal from corner 0 to 7,
+                    // split the same way in every cell so the faces match
+                    for (a, b) in [(1, 2), (1, 4), (2, 1), (2, 4), (4, 1), (4, 2)] {
+                        let tetrahedron = [0, a, a | b, 7].map(corner);
+                        let (inside, outside): (Vec<usize>, Vec<usize>) =
+                            tetrahedron.iter().partition(|&&g| values[g] < 0.0);
+                        let mut faces = Vec::new();
+                        match (inside.as_slice(), outside.as_slice()) {
+                            ([a], [b, c, d]) | ([b, c, d], [a]) => {
+                                faces.push([(*a, *b), (*a, *c), (*a, *d)]);
+                            }
+                            ([a, b], [c, d]) => {
+                                faces.push([(*a, *c), (*a, *d), (*b, *d)]);
+                                faces.push([(*a, *c), (*b, *d), (*b, *c)]);
+                            }
+                            _ => continue,
+                        }
+
+                        let centroid = |corners: &[usize]| {
+                            corners
+                                .iter()
+                                .fold(Vector::default(), |sum, &g| sum.add(point(g)))
+                                .div_scalar(corners.len() as f64)
+                        };
+                        let outwards = centroid(&outside).sub(centroid(&inside));
+                        for face in faces {
+                            let [v1, v2, v3] = face.map(|(a, b)| crossing(a, b, &mut vertices));
+                            let n = normal([v1, v2, v3].into_iter().map(|v| vertices[v]));
+                            if n.dot(outwards) < 0.0 {
+                                triangles.extend([v1, v3, v2]);
+                            } else {
+                                triangles.extend([v1, v2, v3]);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Self::builder(vertices, triangles).build()
+    }
+
+    /// Builds a mesh from vertices projected onto the sphere of the given radius,
+    /// with every face wound to point away from the origin.
+    fn regular_polyhedron(vertices: &[Vector], faces: &[[usize; 3]], radius: f64) -> Self {
+        let vertices: Vec<Vector> = vertices
+            .iter()
+            .map(|v| v.normalize().mul_scalar(radius))
+            .collect();
+        let triangles = faces
+            .iter()
+            .flat_map(|&[a, b, c]| {
+                let n = normal([a, b, c].into_iter().map(|i| vertices[i]));
+                if n.dot(vertices[a]) < 0.0 {
+                    [a, c, b]
+                } else {
+                    [a, b, c]
+                }
+            })
+            .collect();
+        Self::builder(vertices, triangles).build()
+    }
+
+    /// Box from `min` to `max` with its edges and corners rounded off to
+    /// `radius`, like a box with cylindrical edges and spherical corners.
+    ///
+    /// Every quarter circle of the rounding is made of `segments` faces, and
+    /// each flat side is a single quad split into two triangles. `radius` is
+    /// clamped to half the smallest side, where the box becomes a capsule or
+    /// a sphere, and a radius of zero gives a plain box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Mesh, Shape, Vector};
+    ///
+    /// let (min, max) = (Vector::new(-2.0, -1.0, -1.0), Vector::new(2.0, 1.0, 1.0));
+    /// let mesh = Mesh::rounded_box(min, max, 0.5, 4);
+    /// assert!(mesh.bounding_box().min.all_close(min));
+    /// assert!(mesh.bounding_box().max.all_close(max));
+    /// assert_eq!(mesh.filter_paths(|edges| edges.len() != 2).len(), 0);
+    ///
+    /// // Every vertex lies on the rounded surface
+    /// let inner = Vector::new(1.5, 0.5, 0.5);
+    /// assert!(mesh.vertices.iter().all(|v| {
+    ///     let d = v.map(f64::abs).sub(inner).max(Vector::default()).length();
+    ///     (d - 0.5).abs() < 1e-9
+    /// }));
+    ///
+    /// // Without rounding it is a box of 12 triangles
+    /// let mesh = Mesh::rounded_box(min, max, 0.0, 4);
+    /// assert_eq!(mesh.vertices.len(), 8);
+    /// assert_eq!(mesh.triangles.len() / 3, 12);
+    /// ```
+    pub fn rounded_box(min: Vector, max: Vector, radius: f64, segments: usize) -> Self {
+        let half = max.sub(min).div_scalar(2.0);
+        let center = min.add(half);
+        let radius = radius.clamp(0.0, half.min_component());
+        let inner = half.sub_scalar(radius);
+
+        // Each side of a unit cube is a grid of 2 * segments + 1 cells. The
+        // middle cell of every row is stretched over the flat part, and the
+        // others are bent around the rounding.
+        let n = 2 * segments.max(1) + 1;
+        let h = 0.5 / n as f64;
+        let place = |p: Vector| {
+            let sign = p.map(f64::signum);
+            let normal = p.sub(sign.mul_scalar(h)).normalize();
+            center.add(inner.mul(sign)).add(normal.mul_scalar(radius))
+        };
+
+        let axes = [
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+            Vector::new(0.0, 0.0, 1.0),
+        ];
+        let mut merger = VertexMerger::new(1e-6);
+        let mut triangles = Vec::with_capacity(6 * n * n * 6);
+        for a in 0..3 {
+            for s in [1.0, -1.0] {
+                // Tangents with u x v pointing out of the side
+                let (u, v) = if s > 0.0 {
+                    (axes[(a + 1) % 3], axes[(a + 2) % 3])
+                } else {
+                    (axes[(a + 2) % 3], axes[(a + 1) % 3])
+                };
+                let base = axes[a].mul_scalar(0.5 * s);
+                let mut index = |i: usize, j: usize| {
+                    let (x, y) = (i as f64 / n as f64 - 0.5, j as f64 / n as f64 - 0.5);
+                    merger.get_or_insert(place(base.add(u.mul_scalar(x)).add(v.mul_scalar(y))))
+                };
+                for j in 0..n {
+                    for i in 0..n {
+                        let [p, q, r, t] = [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)]
+                            .map(|(i, j)| index(i, j));
+                        for tri in [[p, q, r], [p, r, t]] {
+                            // Cells of the rounding collapse when the radius is zero
+                            if tri[0] != tri[1] && tri[1] != tri[2] && tri[2] != tri[0] {
+                                triangles.extend(tri);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Self::builder(merger.vertices, triangles).build()
+    }
+
+    /// Smooths the mesh with `iterations` steps of Loop subdivision.
+    ///
+    /// Every step splits each triangle into four and moves the vertices toward
+    /// a weighted average of their neighbours, so the surface approaches a
+    /// smooth limit surface and silhouettes lose their facets. Boundary edges,
+    /// i.e. edges not shared by exactly two faces, are smoothed as curves of
+    /// their own. The texture is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::Mesh;
+    ///
+    /// let mesh = Mesh::tetrahedron(1.0).subdivide(1);
+    /// // 4 old vertices plus one per edge
+    /// assert_eq!(mesh.vertices.len(), 4 + 6);
+    /// assert_eq!(mesh.triangles.len() / 3, 4 * 4);
+    /// assert_eq!(mesh.filter_paths(|edges| edges.len() != 2).len(), 0);
+    ///
+    /// // The subdivided surface lies inside the control mesh
+    /// assert!(mesh.vertices.iter().all(|v| v.length() < 1.0));
+    /// ```
+    pub fn subdivide(&self, iterations: usize) -> Self {
+        let mut vertices = self.vertices.clone();
+        let mut triangles = self.triangles.clone();
+
+        for _ in 0..iterations {
+            let edges = edge_opposites(&triangles);
+            let (neighbours, boundary) = vertex_neighbours(vertices.len(), &edges);
+
+            let mut new_vertices: Vec<Vector> = vertices
+                .iter()
+                .enumerate()
+                .map(|(i, &v)| {
+                    let sum = |ids: &[usize]| {
+                        ids.iter()
+                            .fold(Vector::default(), |sum, &j| sum.add(vertices[j]))
+                    };
+                    match (boundary[i].len(), neighbours[i].len()) {
+                        (0, 0) => v,
+                        (0, n) => {
+                            let beta = if n > 3 {
+                                3.0 / (8.0 * n as f64)
+                            } else {
+                                3.0 / 16.0
+                            };
+                            v.mul_scalar(1.0 - n as f64 * beta)
+                                .add(sum(&neighbours[i]).mul_scalar(beta))
+                        }
+                        (2, _) => v.mul_scalar(0.75).add(sum(&boundary[i]).mul_scalar(0.125)),
+                        // Corners where several boundaries meet stay in place
+                        _ => v,
+                    }
+                })
+                .collect();
+
+            let mut edge_points = HashMap::with_capacity(edges.len());
+            for &((a, b), ref opposites) in &edges {
+                let mid = vertices[a].add(vertices[b]);
+                let v = match opposites.as_slice() {
+                    [c, d] => mid
+                        .mul_scalar(0.375)
+                        .add(vertices[*c].add(vertices[*d]).mul_scalar(0.125)),
+                    _ => mid.mul_scalar(0.5),
+                };
+                edge_points.insert((a, b), new_vertices.len());
+                new_vertices.push(v);
+            }
+
+            let mid = |a: usize, b: usize| edge_points[&(a.min(b), a.max(b))];
+            triangles = triangles
+                .chunks_exact(3)
+                .flat_map(|t| {
+                    let [a, b, c] = [t[0], t[1], t[2]];
+                    let [ab, bc, ca] = [mid(a, b), mid(b, c), mid(c, a)];
+                    [a, ab, ca, b, bc, ab, c, ca, bc, ab, bc, ca]
+                })
+                .collect();
+            vertices = new_vertices;
+        }
+
+        Self::builder(vertices, triangles)
+            .texture(self.texture.clone())
+            .build()
+    }
+
+    /// Draws boundary edges and edges where the adjacent faces meet at more
+    /// than `degrees`, see [`MeshTexture::Crease`].
+    ///
+    /// This keeps the feature lines of smooth organic meshes while hiding
+    /// the triangulation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Mesh, Vector, render};
+    ///
+    /// // Neighbouring faces of an icosahedron meet at about 41.8 degrees
+    /// let paths = |degrees| {
+    ///     let mesh = Mesh::icosahedron(1.0).with_crease_angle(degrees);
+    ///     render(vec![mesh]).eye(Vector::new(4.0, 3.0, 2.0)).step(0.0).call()
+    /// };
+    /// assert!(paths(30.0).len() > 0);
+    /// assert_eq!(paths(60.0).len(), 0);
+    /// ```
+    pub fn with_crease_angle(mut self, degrees: f64) -> Self {
+        self.texture = MeshTexture::Crease(degrees);
+        self
+    }
+
     pub fn fit_inside(&self, bx: BBox, anchor: Vector) -> Matrix {
         let scale = bx.size().div(self.bx.size()).min_component();
         let extra = bx.size().sub(self.bx.size().mul_scalar(scale));
@@ -68,6 +621,80 @@ impl Mesh {
         matrix
     }
 
+    /// Converts the mesh to a Wavefront OBJ string.
+    ///
+    /// Every vertex is written as a `v` line and every triangle as an `f`
+    /// line with 1-based indices, so [`load_obj`](crate::load_obj) restores
+    /// the same topology.
+    pub fn to_obj(&self) -> String {
+        let mut obj = String::new();
+        for v in &self.vertices {
+            obj.push_str(&format!("v {} {} {}\n", v.x, v.y, v.z));
+        }
+        for t in self.triangles.chunks_exact(3) {
+            obj.push_str(&format!("f {} {} {}\n", t[0] + 1, t[1] + 1, t[2] + 1));
+        }
+        obj
+    }
+
+    /// Writes the mesh to an OBJ file.
+    ///
+    /// See [`to_obj`](Mesh::to_obj) for details on the output.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Mesh, load_obj};
+    ///
+    /// let mesh = Mesh::icosahedron(1.0);
+    /// let path = std::env::temp_dir().join("larnt_icosahedron.obj");
+    /// let path = path.to_str().unwrap();
+    /// mesh.write_to_obj(path).unwrap();
+    ///
+    /// let reloaded = load_obj(path).unwrap();
+    /// assert_eq!(reloaded.triangles.len(), mesh.triangles.len());
+    /// assert_eq!(
+    ///     reloaded.filter_paths(|edges| edges.len() == 2).len(),
+    ///     mesh.filter_paths(|edges| edges.len() == 2).len(),
+    /// );
+    /// ```
+    pub fn write_to_obj(&self, path: &str) -> std::io::Result<()> {
+        let obj = self.to_obj();
+        std::fs::write(path, obj)
+    }
+
+    /// Returns every edge of the mesh, as a pair of vertex indices, for which
+    /// `group_keeper` returns `true`.
+    ///
+    /// `group_keeper` is called once per edge with the `(min, max, face)`
+    /// entries of all faces sharing it, in ascending face order. The edges
+    /// come out sorted by their vertex indices, so the paths of a mesh, and
+    /// the files written from them, are the same on every run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Camera, Mesh, MeshTexture, Shape, Vector};
+    ///
+    /// let mesh = Mesh::icosahedron(1.0).subdivide(1);
+    /// let edges = mesh.filter_paths(|_| true);
+    /// assert_eq!(edges.len(), 120);
+    /// let pairs: Vec<&[usize]> = edges.iter_paths().collect();
+    /// assert!(pairs.windows(2).all(|w| w[0] < w[1]));
+    ///
+    /// // Rendering the same mesh twice gives the same paths in the same order
+    /// let args = Camera::builder(Vector::new(4.0, 3.0, 2.0)).build().render_args();
+    /// for texture in [MeshTexture::Triangles, MeshTexture::silhouette().call()] {
+    ///     let build = || {
+    ///         Mesh::builder(mesh.vertices.clone(), mesh.triangles.clone())
+    ///             .texture(texture.clone())
+    ///             .build()
+    ///     };
+    ///     let (a, b) = (build().paths(&args), build().paths(&args));
+    ///     assert!(a.len() > 0);
+    ///     assert!(a.iter_paths().eq(b.iter_paths()));
+    /// }
+    /// ```
     pub fn filter_paths(
         &self,
         group_keeper: impl Fn(&[(usize, usize, usize)]) -> bool,
@@ -91,7 +718,7 @@ impl Mesh {
                     ]
                 })
                 .collect::<Vec<_>>();
-            edges.sort_unstable_by_key(|e| (e.0, e.1));
+            edges.sort_unstable();
             edges
         };
 
@@ -143,6 +770,30 @@ impl Mesh {
         .map(|i| self.vertices[i])
     }
 
+    pub fn crease_paths(&self, _args: &RenderArgs, degrees: f64) -> Paths<Vector> {
+        let cos_threshold = degrees.to_radians().cos();
+        let face_normals: Vec<Vector> = self
+            .triangles
+            .chunks_exact(3)
+            .map(|chunk| normal(chunk.iter().map(|&i| self.vertices[i])).normalize())
+            .collect();
+        self.filter_paths(|edges| {
+            if edges.len() == 2 {
+                let (a, b) = (edges[0].2, edges[1].2);
+                let key = if a < b { (a, b) } else { (b, a) };
+                let mut cos_theta = face_normals[a].dot(face_normals[b]);
+                if self.flipped_triangles.contains(&key) {
+                    cos_theta = -cos_theta;
+                }
+                cos_theta < cos_threshold
+            } else {
+                true
+            }
+        })
+        .splice_exact()
+        .map(|i| self.vertices[i])
+    }
+
     pub fn silhouette_paths(&self, args: &RenderArgs, cos_theta: f64) -> Paths<Vector> {
         if cos_theta > 0.0 {
             self.silhouette_inner(
@@ -186,7 +837,7 @@ impl Mesh {
                     dot1mul2 <= 0.0
                 }
             } else {
-                return true;
+                true
             }
         })
         .splice_exact()
@@ -194,6 +845,84 @@ impl Mesh {
     }
 }
 
+#[bon]
+impl Mesh {
+    /// Smooths the mesh with `iterations` steps of Laplacian relaxation.
+    ///
+    /// Every step moves each vertex by `lambda` (typically in `0..=1`) of the
+    /// way toward the average of its neighbours. Boundary vertices, on edges
+    /// not shared by exactly two faces, only follow their boundary neighbours,
+    /// or stay in place with `fix_boundary`. The triangles and the texture are
+    /// kept, only the vertices move.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::Mesh;
+    ///
+    /// // A bumpy sphere gets rounder
+    /// let mut bumpy = Mesh::icosahedron(1.0).subdivide(2);
+    /// for (i, v) in bumpy.vertices.iter_mut().enumerate() {
+    ///     *v = v.normalize().mul_scalar(if i % 2 == 0 { 1.1 } else { 0.9 });
+    /// }
+    /// let bumpy = Mesh::builder(bumpy.vertices, bumpy.triangles).build();
+    /// let spread = |mesh: &Mesh| {
+    ///     let radii = mesh.vertices.iter().map(|v| v.length());
+    ///     radii.clone().fold(0.0, f64::max) - radii.fold(f64::INFINITY, f64::min)
+    /// };
+    /// let smooth = bumpy.smooth(5, 0.5).call();
+    /// assert_eq!(smooth.triangles, bumpy.triangles);
+    /// assert!(spread(&smooth) < spread(&bumpy) / 2.0);
+    ///
+    /// // With one face removed, the corners of the hole stay put
+    /// let ico = Mesh::icosahedron(1.0);
+    /// let hole = ico.triangles[..3].to_vec();
+    /// let open = Mesh::builder(ico.vertices, ico.triangles[3..].to_vec()).build();
+    /// let smooth = open.smooth(3, 0.5).fix_boundary().call();
+    /// for i in 0..open.vertices.len() {
+    ///     assert_eq!(smooth.vertices[i] == open.vertices[i], hole.contains(&i));
+    /// }
+    /// ```
+    #[builder]
+    pub fn smooth(
+        &self,
+        #[builder(start_fn)] iterations: usize,
+        #[builder(start_fn)] lambda: f64,
+        #[builder(default, with = || true)] fix_boundary: bool,
+    ) -> Self {
+        let edges = edge_opposites(&self.triangles);
+        let (neighbours, boundary) = vertex_neighbours(self.vertices.len(), &edges);
+
+        let mut vertices = self.vertices.clone();
+        for _ in 0..iterations {
+            vertices = vertices
+                .iter()
+                .enumerate()
+                .map(|(i, &v)| {
+                    let ids = match (boundary[i].is_empty(), fix_boundary) {
+                        (true, _) => &neighbours[i],
+                        (false, true) => return v,
+                        (false, false) => &boundary[i],
+                    };
+                    if ids.is_empty() {
+                        return v;
+                    }
+                    let average = ids
+                        .iter()
+                        .fold(Vector::default(), |sum, &j| sum.add(vertices[j]))
+                        .div_scalar(ids.len() as f64);
+                    v.add(average.sub(v).mul_scalar(lambda))
+                })
+                .collect();
+        }
+
+        Self::builder(vertices, self.triangles.clone())
+            .flipped_triangles(self.flipped_triangles.clone())
+            .texture(self.texture.clone())
+            .build()
+    }
+}
+
 impl AsRef<Triangle> for Triangle {
     fn as_ref(&self) -> &Triangle {
         self
@@ -201,17 +930,17 @@ impl AsRef<Triangle> for Triangle {
 }
 
 pub trait TriangleMesh {
-    fn triangles(&self) -> impl Iterator<Item = impl AsRef<Triangle>> + ExactSizeIterator;
+    fn triangles(&self) -> impl ExactSizeIterator<Item = impl AsRef<Triangle>>;
 }
 
 impl TriangleMesh for Mesh {
-    fn triangles(&self) -> impl Iterator<Item = impl AsRef<Triangle>> + ExactSizeIterator {
+    fn triangles(&self) -> impl ExactSizeIterator<Item = impl AsRef<Triangle>> {
         self.tree.shapes().iter()
     }
 }
 
 impl TriangleMesh for TransformedShape<Mesh> {
-    fn triangles(&self) -> impl Iterator<Item = impl AsRef<Triangle>> + ExactSizeIterator {
+    fn triangles(&self) -> impl ExactSizeIterator<Item = impl AsRef<Triangle>> {
         self.shape.tree.shapes().iter().map(|triangle| {
             Triangle::new(
                 self.matrix.mul_position(triangle.v1),
@@ -240,14 +969,56 @@ impl Shape for Mesh {
             MeshTexture::Triangles => self.triangle_paths(args),
             MeshTexture::Polygonal => self.polygonal_paths(args),
             MeshTexture::Silhouette(cos_theta) => self.silhouette_paths(args, cos_theta),
+            MeshTexture::Crease(degrees) => self.crease_paths(args, degrees),
+        }
+    }
+
+    fn view_dependent(&self) -> bool {
+        matches!(self.texture, MeshTexture::Silhouette(_))
+    }
+}
+
+/// Lists every edge `(min, max)` with the third vertices of the faces sharing
+/// it, in order of first appearance so the results do not depend on hashing.
+fn edge_opposites(triangles: &[usize]) -> Vec<((usize, usize), Vec<usize>)> {
+    let mut index: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut edges: Vec<((usize, usize), Vec<usize>)> = Vec::new();
+    for t in triangles.chunks_exact(3) {
+        for (a, b, c) in [(t[0], t[1], t[2]), (t[1], t[2], t[0]), (t[2], t[0], t[1])] {
+            let key = (a.min(b), a.max(b));
+            let i = *index.entry(key).or_insert_with(|| {
+                edges.push((key, Vec::new()));
+                edges.len() - 1
+            });
+            edges[i].1.push(c);
+        }
+    }
+    edges
+}
+
+/// Returns the neighbours of every vertex, and separately its neighbours
+/// along boundary edges, those not shared by exactly two faces.
+fn vertex_neighbours(
+    len: usize,
+    edges: &[((usize, usize), Vec<usize>)],
+) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
+    let mut neighbours = vec![Vec::new(); len];
+    let mut boundary = vec![Vec::new(); len];
+    for ((a, b), opposites) in edges {
+        let (a, b) = (*a, *b);
+        neighbours[a].push(b);
+        neighbours[b].push(a);
+        if opposites.len() != 2 {
+            boundary[a].push(b);
+            boundary[b].push(a);
         }
     }
+    (neighbours, boundary)
 }
 
 fn normal(mut v123: impl Iterator<Item = Vector>) -> Vector {
     let [v1, v2, v3] = std::array::from_fn(|_| v123.next().unwrap());
-    let normal = (v2.sub(v1)).cross(v3.sub(v1)).normalize();
-    normal
+    (v2.sub(v1)).cross(v3.sub(v1)).normalize()
 }
 
 struct VertexMerger {
@@ -1,12 +1,14 @@
 //! Path handling and output.
 //!
 //! This module provides types for working with 2D/3D paths and outputting
-//! them to various formats like PNG and SVG.
+//! them to various formats like PNG, SVG, DXF and EPS.
 //!
 //! # Types
 //!
 //! - [`Paths`]: A collection of paths (the primary output type from rendering)
 //! - [`NewPath`]: A builder for appending a single path into a [`Paths`] collection
+//! - [`ColoredPaths`]: A collection of paths with a stroke color per path
+//! - [`SvgStyle`]: Stroke attributes for SVG output
 //!
 //! # Example
 //!
@@ -26,9 +28,9 @@ use crate::filter::Filter;
 use crate::matrix::Matrix;
 use crate::shape::RenderArgs;
 use crate::vector::Vector;
-use bon::bon;
+use bon::{Builder, bon};
 #[cfg(feature = "image")]
-use image::{ImageBuffer, Pixel, Rgba};
+use image::{ImageBuffer, Rgba};
 use std::collections::HashMap;
 use std::f64::consts::PI;
 use std::io::Write;
@@ -80,6 +82,10 @@ impl<T> Paths<T> {
     }
 
     /// Extends this collection with paths from another.
+    ///
+    /// This inherent method takes precedence over the [`Extend`] impls in
+    /// method call syntax, call those as `Extend::extend(&mut paths, iter)`
+    /// to append single paths.
     pub fn extend(&mut self, other: Self) {
         self.offsets
             .extend(other.offsets.into_iter().map(|o| o + self.buffer.len()));
@@ -120,6 +126,13 @@ impl<T> Paths<T> {
         self.offsets.len()
     }
 
+    /// Returns the number of line segments, i.e. pen strokes, in all paths.
+    pub fn num_segments(&self) -> usize {
+        self.iter_paths()
+            .map(|path| path.len().saturating_sub(1))
+            .sum()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.offsets.is_empty()
     }
@@ -145,21 +158,117 @@ impl<T> Paths<T> {
     }
 }
 
-impl<'a, T> std::ops::Index<usize> for Paths<T> {
+impl<T> std::ops::Index<usize> for Paths<T> {
     type Output = [T];
     fn index(&self, index: usize) -> &Self::Output {
-        self.get(index).expect(&format!(
-            "index out of bounds: the len is {} but the index is {index}",
-            self.len()
-        ))
+        let len = self.len();
+        self.get(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
     }
 }
-impl<'a, T> std::ops::IndexMut<usize> for Paths<T> {
+impl<T> std::ops::IndexMut<usize> for Paths<T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         let len = self.len();
-        self.get_mut(index).expect(&format!(
-            "index out of bounds: the len is {len} but the index is {index}",
-        ))
+        self.get_mut(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+/// Concatenates several collections, e.g. separately rendered parts of a scene.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Paths, Vector};
+///
+/// let background: Paths<Vector> = [vec![Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0)]]
+///     .into_iter()
+///     .collect();
+/// let mut foreground = Paths::new();
+/// let line = [Vector::new(0.0, 1.0, 0.0), Vector::new(1.0, 1.0, 0.0)];
+/// Extend::extend(&mut foreground, [line.as_slice()]);
+///
+/// let paths: Paths<Vector> = [background, foreground].into_iter().collect();
+/// assert_eq!(paths.len(), 2);
+/// assert_eq!(paths[1][0], Vector::new(0.0, 1.0, 0.0));
+/// ```
+impl<T> FromIterator<Paths<T>> for Paths<T> {
+    fn from_iter<I: IntoIterator<Item = Paths<T>>>(iter: I) -> Self {
+        let mut result = Paths::new();
+        for paths in iter {
+            result.extend(paths);
+        }
+        result
+    }
+}
+
+/// Collects single paths, empty ones are skipped.
+impl<T> FromIterator<Vec<T>> for Paths<T> {
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
+        let mut result = Paths::new();
+        Extend::extend(&mut result, iter);
+        result
+    }
+}
+
+/// Appends single paths, empty ones are skipped.
+///
+/// Method call syntax picks [`Paths::extend`], which appends a whole
+/// collection, so call this as `Extend::extend(&mut paths, iter)`.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Paths, Vector};
+///
+/// let mut paths = Paths::new();
+/// paths.new_path().extend([Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0)]);
+///
+/// // Single paths, through the trait
+/// let line = vec![Vector::new(0.0, 1.0, 0.0), Vector::new(1.0, 1.0, 0.0)];
+/// Extend::extend(&mut paths, [line, vec![]]);
+/// assert_eq!(paths.len(), 2);
+///
+/// // A whole collection, through the inherent method
+/// paths.extend(paths.clone());
+/// assert_eq!(paths.len(), 4);
+/// assert_eq!(paths[3][0], Vector::new(0.0, 1.0, 0.0));
+/// ```
+impl<T> Extend<Vec<T>> for Paths<T> {
+    fn extend<I: IntoIterator<Item = Vec<T>>>(&mut self, iter: I) {
+        for path in iter {
+            self.new_path().extend(path);
+        }
+    }
+}
+
+/// Appends single paths, empty ones are skipped.
+impl<'a, T: Copy + 'a> Extend<&'a [T]> for Paths<T> {
+    fn extend<I: IntoIterator<Item = &'a [T]>>(&mut self, iter: I) {
+        for path in iter {
+            self.new_path().extend_from_slice(path);
+        }
+    }
+}
+
+/// Paths are (de)serialized as a list of paths, each a list of points.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Paths<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter_paths())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Paths<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut paths = Paths::new();
+        for path in <Vec<Vec<T>> as serde::Deserialize>::deserialize(deserializer)? {
+            paths.new_path().extend(path);
+        }
+        Ok(paths)
     }
 }
 
@@ -172,6 +281,95 @@ impl Paths<Vector> {
     /// * `width` - The image width
     /// * `height` - The image height
     /// * `linewidth` - The thickness of the lines in pixels
+    /// * `background` - The background color, may be transparent (default: white)
+    /// * `foreground` - The line color (default: black)
+    /// * `depth_cue` - Line thickness at the nearest and farthest depth, overriding `linewidth`
+    /// * `supersample` - Draw at this many times the resolution, then average
+    ///   down to the requested size (default: 1)
+    ///
+    /// Anti-aliased line edges are alpha composited over what is already drawn,
+    /// so any combination of colors, including translucent lines and
+    /// transparent backgrounds, blends correctly.
+    ///
+    /// ```
+    /// use image::Rgba;
+    /// use larnt::{Paths, Vector};
+    ///
+    /// let mut paths = Paths::new();
+    /// paths.new_path().extend([Vector::new(10.0, 10.3, 0.0), Vector::new(90.0, 10.3, 0.0)]);
+    ///
+    /// // White lines on a transparent background: edge pixels stay white and
+    /// // only fade out through their alpha
+    /// let img = paths
+    ///     .to_image(100.0, 20.0)
+    ///     .background(Rgba([0, 0, 0, 0]))
+    ///     .foreground(Rgba([255, 255, 255, 255]))
+    ///     .call();
+    /// assert!(img.pixels().all(|p| p[3] == 0 || p.0[..3] == [255, 255, 255]));
+    /// assert!(img.pixels().any(|p| p[3] > 0 && p[3] < 255));
+    ///
+    /// // On a dark opaque background the edges fade between the two colors
+    /// let img = paths
+    ///     .to_image(100.0, 20.0)
+    ///     .background(Rgba([0, 0, 64, 255]))
+    ///     .foreground(Rgba([255, 255, 255, 255]))
+    ///     .call();
+    /// assert!(img.pixels().all(|p| p[3] == 255 && p[0] == p[1] && p[2] >= p[0]));
+    /// ```
+    ///
+    /// # Supersampling
+    ///
+    /// With `supersample(n)`, the lines are drawn on an `n * width` by
+    /// `n * height` buffer and each `n` by `n` block is averaged into one
+    /// output pixel, which smooths thin lines at small sizes. The intermediate
+    /// buffer takes `n²` times the memory of the output image, 4 bytes per
+    /// pixel, so 4 already costs 16 times as much.
+    ///
+    /// ```
+    /// use larnt::{Sphere, Vector, render};
+    ///
+    /// let sphere = Sphere::builder(Vector::new(0.0, 0.0, 0.0), 1.0).build();
+    /// let paths = render(vec![sphere]).eye(Vector::new(4.0, 3.0, 2.0)).width(128.0).height(128.0).call();
+    /// let img = paths.to_image(128.0, 128.0).supersample(4).call();
+    /// assert_eq!(img.dimensions(), (128, 128));
+    ///
+    /// // A canvas without area gives an empty image, the paths are skipped
+    /// assert_eq!(paths.to_image(0.0, 0.0).linewidth(1.0).call().dimensions(), (0, 0));
+    /// ```
+    ///
+    /// # Depth Cue
+    ///
+    /// Paths returned by [`render`](crate::render) keep a z coordinate in
+    /// normalized device depth: `-1` at the near plane and `1` at the far plane,
+    /// increasing monotonically with the distance from the eye. With `depth_cue`,
+    /// each segment's thickness is interpolated between the two widths by the
+    /// segment's depth relative to the nearest and farthest points drawn. If all
+    /// points share the same z (e.g. paths built by hand in 2D) the near width is
+    /// used throughout.
+    ///
+    /// ```
+    /// use larnt::{Cube, Vector, render};
+    ///
+    /// let cube = Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build();
+    /// let paths = render(vec![cube]).eye(Vector::new(4.0, 3.0, 2.0)).call();
+    /// let img = paths.to_image(1024.0, 1024.0).depth_cue(3.0, 0.5).call();
+    /// ```
+    ///
+    /// Segments are split by their length on the image, at most 256 times, so
+    /// segments reaching far off the image or with NaN coordinates are cheap:
+    ///
+    /// ```
+    /// use larnt::{Paths, Vector};
+    ///
+    /// let paths: Paths<Vector> = [
+    ///     vec![Vector::new(0.0, 0.0, 0.0), Vector::new(1e15, 1e15, 1.0)],
+    ///     vec![Vector::new(0.0, 0.0, 0.0), Vector::new(f64::NAN, 8.0, 1.0)],
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// let img = paths.to_image(64.0, 64.0).depth_cue(3.0, 0.5).call();
+    /// assert_eq!(img.dimensions(), (64, 64));
+    /// ```
     #[cfg(feature = "image")]
     #[builder]
     pub fn to_image(
@@ -181,30 +379,211 @@ impl Paths<Vector> {
         #[builder(default = 1.0)] linewidth: f64,
         #[builder(default = Rgba([255, 255, 255, 255]))] background: Rgba<u8>,
         #[builder(default = Rgba([0, 0, 0, 255]))] foreground: Rgba<u8>,
+        #[builder(with = |near_width: f64, far_width: f64| (near_width, far_width))]
+        depth_cue: Option<(f64, f64)>,
+        #[builder(default = 1)] supersample: u32,
     ) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
-        let scale = 1.0;
-        let w = (width * scale) as u32;
-        let h = (height * scale) as u32;
+        let ss = supersample.max(1);
+        let scale = ss as f64;
+        let w = width as u32 * ss;
+        let h = height as u32 * ss;
 
         let mut img: ImageBuffer<Rgba<u8>, Vec<u8>> = ImageBuffer::from_pixel(w, h, background);
 
+        let (z_min, z_max) = self
+            .buffer
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+                (lo.min(v.z), hi.max(v.z))
+            });
+        let segment_width = |z: f64| match depth_cue {
+            Some((near_width, far_width)) if z_max > z_min => {
+                let t = (z - z_min) / (z_max - z_min);
+                near_width + (far_width - near_width) * t
+            }
+            Some((near_width, _)) => near_width,
+            None => linewidth,
+        };
+
         for path_points in self.iter_paths() {
             for i in 0..path_points.len().saturating_sub(1) {
                 let p1 = &path_points[i];
                 let p2 = &path_points[i + 1];
-                draw_line(
-                    &mut img,
-                    p1.x * scale,
-                    h as f64 - p1.y * scale,
-                    p2.x * scale,
-                    h as f64 - p2.y * scale,
-                    linewidth,
-                    foreground,
-                );
+                // Long segments are split so the depth-cued width varies along
+                // them, by their length on the image and at most 256 times (a
+                // NaN length casts to zero pieces, which `max` turns into one)
+                let pieces = match depth_cue {
+                    Some(_) => {
+                        let length = (p2.x - p1.x).hypot(p2.y - p1.y);
+                        ((length * scale / 8.0).ceil().clamp(1.0, 256.0) as usize).max(1)
+                    }
+                    None => 1,
+                };
+                for k in 0..pieces {
+                    let a = p1.add(p2.sub(*p1).mul_scalar(k as f64 / pieces as f64));
+                    let b = p1.add(p2.sub(*p1).mul_scalar((k + 1) as f64 / pieces as f64));
+                    draw_line(
+                        &mut img,
+                        a.x * scale,
+                        h as f64 - a.y * scale,
+                        b.x * scale,
+                        h as f64 - b.y * scale,
+                        segment_width((a.z + b.z) / 2.0) * scale,
+                        foreground,
+                    );
+                }
             }
         }
 
-        img
+        if ss > 1 { downsample(&img, ss) } else { img }
+    }
+
+    /// Writes the paths to a PNG image file with drawing options.
+    ///
+    /// Takes the same options as [`to_image`](Paths::to_image), except that
+    /// `linewidth` defaults to 2.5 as in [`write_to_png`](Paths::write_to_png),
+    /// which is the same as calling this without options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use image::Rgba;
+    /// use larnt::{Sphere, Vector, render};
+    ///
+    /// let sphere = Sphere::builder(Vector::new(0.0, 0.0, 0.0), 1.0).build();
+    /// let paths = render(vec![sphere]).eye(Vector::new(4.0, 3.0, 2.0)).width(256.0).height(256.0).call();
+    ///
+    /// // White lines on a dark blue background
+    /// let file = std::env::temp_dir().join("larnt_write_to_png_with.png");
+    /// paths
+    ///     .write_to_png_with(file.to_str().unwrap(), 256.0, 256.0)
+    ///     .linewidth(1.5)
+    ///     .background(Rgba([0, 0, 64, 255]))
+    ///     .foreground(Rgba([255, 255, 255, 255]))
+    ///     .call()
+    ///     .expect("Failed to write PNG");
+    /// # let img = image::open(&file).unwrap().to_rgba8();
+    /// # assert_eq!(img.get_pixel(0, 0), &Rgba([0, 0, 64, 255]));
+    /// # assert!(img.pixels().any(|p| p == &Rgba([255, 255, 255, 255])));
+    /// ```
+    #[cfg(feature = "png")]
+    #[builder]
+    pub fn write_to_png_with(
+        &self,
+        #[builder(start_fn)] path: &str,
+        #[builder(start_fn)] width: f64,
+        #[builder(start_fn)] height: f64,
+        #[builder(default = 2.5)] linewidth: f64,
+        #[builder(default = Rgba([255, 255, 255, 255]))] background: Rgba<u8>,
+        #[builder(default = Rgba([0, 0, 0, 255]))] foreground: Rgba<u8>,
+        #[builder(with = |near_width: f64, far_width: f64| (near_width, far_width))]
+        depth_cue: Option<(f64, f64)>,
+        #[builder(default = 1)] supersample: u32,
+    ) -> Result<(), image::ImageError> {
+        self.to_image(width, height)
+            .linewidth(linewidth)
+            .background(background)
+            .foreground(foreground)
+            .maybe_depth_cue(depth_cue)
+            .supersample(supersample)
+            .call()
+            .save(path)
+    }
+
+    /// Converts the paths to an SVG string with sizing options.
+    ///
+    /// Without options this is the same as [`to_svg`](Paths::to_svg).
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the drawing, in the coordinates of the paths
+    /// * `height` - The height of the drawing, in the coordinates of the paths
+    /// * `units` - A unit suffix for the document size, such as `"mm"` or `"in"`
+    /// * `view_box` - The `viewBox` as `min_x, min_y, width, height`
+    /// * `style` - Stroke attributes shared by all paths (default: the bare
+    ///   style of [`to_svg`](Paths::to_svg))
+    ///
+    /// With `units`, the document is `width` by `height` of that unit and the
+    /// `viewBox` defaults to `0 0 width height`, so one unit of the paths is
+    /// one unit on paper. A plotter file of A4 size, with paths rendered at
+    /// `.width(210.0).height(297.0)`:
+    ///
+    /// ```
+    /// use larnt::{Paths, Vector};
+    ///
+    /// let mut paths = Paths::new();
+    /// paths.new_path().extend([Vector::new(10.0, 10.0, 0.0), Vector::new(200.0, 287.0, 0.0)]);
+    ///
+    /// let svg = paths.to_svg_with(210.0, 297.0).units("mm").call();
+    /// assert!(svg.starts_with("<svg width=\"210mm\" height=\"297mm\" viewBox=\"0 0 210 297\""));
+    /// assert_eq!(paths.to_svg_with(210.0, 297.0).call(), paths.to_svg(210.0, 297.0));
+    /// ```
+    #[builder]
+    pub fn to_svg_with(
+        &self,
+        #[builder(start_fn)] width: f64,
+        #[builder(start_fn)] height: f64,
+        #[builder(into)] units: Option<String>,
+        #[builder(with = |min_x: f64, min_y: f64, width: f64, height: f64| (min_x, min_y, width, height))]
+        view_box: Option<(f64, f64, f64, f64)>,
+        #[builder(default)] style: SvgStyle,
+    ) -> String {
+        let view_box = view_box.or(units.as_ref().map(|_| (0.0, 0.0, width, height)));
+        // The stroke color moves to the group when styled, a per-path
+        // stroke would override it
+        let stroke = style.color.is_none().then(|| "black".to_string());
+        svg_document(
+            width,
+            height,
+            units.as_deref().unwrap_or_default(),
+            view_box,
+            &style.attributes(),
+            self.iter_paths()
+                .map(|path| path_to_svg(path, stroke.as_deref())),
+        )
+    }
+
+    /// Writes the paths to an SVG file with sizing options.
+    ///
+    /// Takes the same options as [`to_svg_with`](Paths::to_svg_with), without
+    /// options this is the same as [`write_to_svg`](Paths::write_to_svg).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Cube, LineCap, SvgStyle, Vector, render};
+    ///
+    /// let cube = Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build();
+    /// let paths = render(vec![cube]).eye(Vector::new(4.0, 3.0, 2.0)).call();
+    ///
+    /// let style = SvgStyle::builder().stroke_width(2.0).linecap(LineCap::Round).build();
+    /// let file = std::env::temp_dir().join("larnt_write_to_svg_with.svg");
+    /// paths
+    ///     .write_to_svg_with(file.to_str().unwrap(), 1024.0, 1024.0)
+    ///     .style(style.clone())
+    ///     .call()
+    ///     .unwrap();
+    /// # let svg = std::fs::read_to_string(&file).unwrap();
+    /// # assert_eq!(svg, paths.to_svg_with(1024.0, 1024.0).style(style).call());
+    /// ```
+    #[builder]
+    pub fn write_to_svg_with(
+        &self,
+        #[builder(start_fn)] path: &str,
+        #[builder(start_fn)] width: f64,
+        #[builder(start_fn)] height: f64,
+        #[builder(into)] units: Option<String>,
+        #[builder(with = |min_x: f64, min_y: f64, width: f64, height: f64| (min_x, min_y, width, height))]
+        view_box: Option<(f64, f64, f64, f64)>,
+        #[builder(default)] style: SvgStyle,
+    ) -> std::io::Result<()> {
+        let svg = self
+            .to_svg_with(width, height)
+            .maybe_units(units)
+            .maybe_view_box(view_box)
+            .style(style)
+            .call();
+        std::fs::write(path, svg)
     }
 }
 
@@ -222,15 +601,124 @@ impl Paths<Vector> {
     }
 
     /// Applies a transformation matrix to all paths.
-    pub fn transform(self, matrix: &Matrix) -> Self {
-        Self {
-            buffer: self
-                .buffer
-                .into_iter()
-                .map(|v| matrix.mul_position(v))
-                .collect(),
-            offsets: self.offsets,
+    pub fn transform(mut self, matrix: &Matrix) -> Self {
+        matrix.transform_points(&mut self.buffer);
+        self
+    }
+
+    /// Moves 2D paths by `dx` along x and `dy` along y.
+    ///
+    /// Like [`Paths::rotate_2d`] and [`Paths::scale_2d`], this leaves z (the
+    /// depth of rendered paths) as is, so several renders can be arranged on
+    /// one sheet without building a [`Matrix`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Paths, Vector};
+    ///
+    /// let mut paths = Paths::new();
+    /// paths.new_path().extend([Vector::new(0.0, 0.0, 0.5), Vector::new(10.0, 0.0, 0.5)]);
+    ///
+    /// // Two copies side by side, the second one turned upright and halved
+    /// let mut sheet = paths.clone();
+    /// sheet.extend(paths.rotate_2d(90.0).scale_2d(0.5, 0.5).translate_2d(20.0, 0.0));
+    /// assert!(sheet[1][1].sub(Vector::new(20.0, 5.0, 0.5)).length() < 1e-9);
+    /// ```
+    pub fn translate_2d(self, dx: f64, dy: f64) -> Self {
+        self.transform(&Matrix::translate(Vector::new(dx, dy, 0.0)))
+    }
+
+    /// Rotates 2D paths counterclockwise by `degrees` around the origin.
+    ///
+    /// Path coordinates have y pointing up, so the turn is counterclockwise
+    /// in the written images as well. To turn around another point, move it
+    /// to the origin with [`Paths::translate_2d`] first and back afterwards.
+    pub fn rotate_2d(self, degrees: f64) -> Self {
+        self.transform(&Matrix::rotate(
+            Vector::new(0.0, 0.0, 1.0),
+            -degrees.to_radians(),
+        ))
+    }
+
+    /// Scales 2D paths by `sx` along x and `sy` along y, relative to the
+    /// origin.
+    pub fn scale_2d(self, sx: f64, sy: f64) -> Self {
+        self.transform(&Matrix::scale(Vector::new(sx, sy, 1.0)))
+    }
+
+    /// Scales and translates 2D paths to fit a `width` x `height` canvas.
+    ///
+    /// The scale is uniform, so the aspect ratio is kept, and the result is
+    /// centered with at least `margin` on every side. Only x and y are
+    /// changed, z (the depth of rendered paths) is left as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Paths, Vector};
+    ///
+    /// let mut paths = Paths::new();
+    /// paths.new_path().extend([Vector::new(-50.0, 10.0, 0.0), Vector::new(1100.0, 600.0, 0.0)]);
+    ///
+    /// let fitted = paths.scale_to_fit(1024.0, 768.0, 16.0);
+    /// let bx = fitted.bounding_box();
+    /// assert!(bx.min.x >= 16.0 - 1e-9 && bx.max.x <= 1024.0 - 16.0 + 1e-9);
+    /// assert!(bx.min.y >= 16.0 - 1e-9 && bx.max.y <= 768.0 - 16.0 + 1e-9);
+    /// ```
+    pub fn scale_to_fit(&self, width: f64, height: f64, margin: f64) -> Self {
+        if self.buffer.is_empty() {
+            return self.clone();
         }
+        let bx = self.bounding_box();
+        let size = bx.size();
+        let (w, h) = (width - 2.0 * margin, height - 2.0 * margin);
+        let scale = match (size.x > 0.0, size.y > 0.0) {
+            (true, true) => (w / size.x).min(h / size.y),
+            (true, false) => w / size.x,
+            (false, true) => h / size.y,
+            (false, false) => 1.0,
+        };
+        let center = bx.center();
+        let matrix = Matrix::translate(Vector::new(-center.x, -center.y, 0.0))
+            .scaled(Vector::new(scale, scale, 1.0))
+            .translated(Vector::new(width / 2.0, height / 2.0, 0.0));
+        self.clone().transform(&matrix)
+    }
+
+    /// Clips 2D paths to the canvas rectangle `[0, width] x [0, height]`.
+    ///
+    /// Every segment is clipped with the Liang-Barsky algorithm, and a path is
+    /// split wherever it leaves the canvas. The z coordinate is interpolated
+    /// along the clipped segments. Rendered paths can reach far beyond the
+    /// canvas, clipping them keeps exported files small.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Paths, Vector};
+    ///
+    /// // A line entering the canvas from the left, then leaving and
+    /// // re-entering through the top
+    /// let mut paths = Paths::new();
+    /// paths.new_path().extend([
+    ///     Vector::new(-50.0, 50.0, 0.0),
+    ///     Vector::new(50.0, 50.0, 1.0),
+    ///     Vector::new(50.0, 150.0, 1.0),
+    ///     Vector::new(80.0, 50.0, 1.0),
+    /// ]);
+    ///
+    /// let clipped = paths.clip_rect(100.0, 100.0);
+    /// assert_eq!(clipped.len(), 2);
+    /// assert_eq!(clipped[0], [Vector::new(0.0, 50.0, 0.5), Vector::new(50.0, 50.0, 1.0), Vector::new(50.0, 100.0, 1.0)]);
+    /// assert_eq!(clipped[1], [Vector::new(65.0, 100.0, 1.0), Vector::new(80.0, 50.0, 1.0)]);
+    /// ```
+    pub fn clip_rect(&self, width: f64, height: f64) -> Self {
+        let mut result = Paths::new();
+        for path in self.iter_paths() {
+            path_clip_rect(path, width, height, &mut result);
+        }
+        result
     }
 
     /// Subdivides paths into smaller segments.
@@ -246,6 +734,29 @@ impl Paths<Vector> {
         result
     }
 
+    /// Subdivides paths until consecutive points are at most `args.step`
+    /// apart on screen.
+    ///
+    /// Segments entirely outside of the canvas are left whole. Segments with a
+    /// non-finite endpoint are not subdivided.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Camera, Paths, Vector};
+    ///
+    /// let args = Camera::builder(Vector::new(0.0, -5.0, 0.0)).build().render_args();
+    /// let paths: Paths<Vector> = [
+    ///     vec![Vector::new(-1.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0)],
+    ///     vec![Vector::new(0.0, 0.0, 0.0), Vector::new(f64::NAN, 0.0, 0.0)],
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let chopped = paths.chop_adaptive(&args);
+    /// assert!(chopped.iter_paths().next().unwrap().len() > 100);
+    /// assert_eq!(chopped.iter_paths().nth(1).unwrap().len(), 2);
+    /// ```
     pub fn chop_adaptive(&self, args: &RenderArgs) -> Self {
         let mut result = Self::new();
         for path in self.iter_paths() {
@@ -275,6 +786,35 @@ impl Paths<Vector> {
     ///
     /// Uses the Ramer-Douglas-Peucker algorithm to reduce the number of
     /// points while preserving the overall shape.
+    ///
+    /// A closed path, whose first and last points are equal, is first split at
+    /// the point farthest from its start, since the distance to a segment of
+    /// zero length is the distance to its point. Each half is then simplified
+    /// as an open path, so loops keep their shape instead of collapsing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Paths, Vector};
+    ///
+    /// let n = 1000;
+    /// let mut circle: Vec<Vector> = (0..n)
+    ///     .map(|i| {
+    ///         let a = i as f64 / n as f64 * std::f64::consts::TAU;
+    ///         Vector::new(a.cos(), a.sin(), 0.0)
+    ///     })
+    ///     .collect();
+    /// circle.push(circle[0]);
+    ///
+    /// let paths: Paths<Vector> = [circle].into_iter().collect();
+    /// let simplified = paths.simplify(0.01);
+    /// let polygon = simplified.iter_paths().next().unwrap();
+    /// assert!(polygon.len() > 8 && polygon.len() < 100);
+    /// assert_eq!(polygon.first(), polygon.last());
+    /// // Every vertex is still on the circle, and the polygon is not flat
+    /// assert!(polygon.iter().all(|v| (v.length() - 1.0).abs() < 1e-9));
+    /// assert!(simplified.bounding_box().size().y > 1.9);
+    /// ```
     pub fn simplify(&self, threshold: f64) -> Self {
         let mut result = Paths::new();
         for path in self.iter_paths() {
@@ -283,31 +823,182 @@ impl Paths<Vector> {
         result
     }
 
+    /// Joins paths whose endpoints coincide within `tolerance` into longer polylines.
+    ///
+    /// Unlike [`simplify`](Paths::simplify), which works within a single path,
+    /// this stitches separate paths together (reversing them where needed).
+    /// Each endpoint is joined to at most one other endpoint, so at a junction
+    /// where three or more paths meet only two of them are connected and the
+    /// rest are left as separate paths. Chain with [`simplify`](Paths::simplify)
+    /// to also drop nearly collinear interior vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Paths, Vector};
+    ///
+    /// let corners = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].map(|(x, y)| Vector::new(x, y, 0.0));
+    /// let mut paths = Paths::new();
+    /// for i in 0..4 {
+    ///     paths.new_path().extend([corners[i], corners[(i + 1) % 4]]);
+    /// }
+    ///
+    /// let joined = paths.join(1e-6);
+    /// assert_eq!(joined.len(), 1);
+    /// assert_eq!(joined[0].len(), 5);
+    /// assert_eq!(joined[0].first(), joined[0].last());
+    /// ```
+    pub fn join(&self, tolerance: f64) -> Self {
+        if tolerance <= 0.0 {
+            return self.splice_exact();
+        }
+        let cell = |v: &Vector| {
+            let [x, y, z] = [v.x, v.y, v.z].map(|c| (c / tolerance).floor() as i64);
+            (x, y, z)
+        };
+        let tolerance_sq = tolerance * tolerance;
+        self.splice(
+            cell,
+            |v| {
+                let (x, y, z) = cell(v);
+                (-1..=1).flat_map(move |dx| {
+                    (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (x + dx, y + dy, z + dz)))
+                })
+            },
+            |a, b| a.distance_squared(*b) <= tolerance_sq,
+            true,
+        )
+    }
+
+    /// Reorders paths to reduce pen-up travel between them.
+    ///
+    /// Starting from the origin, this repeatedly picks the unvisited path
+    /// whose start or end point is closest (in projected x/y) to the current
+    /// pen position, reversing the path when its end point is the closer one.
+    /// Paths are never split or merged, and the result is deterministic for a
+    /// given input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Paths, Vector};
+    ///
+    /// let mut paths = Paths::new();
+    /// for x in [4.0, 0.0, 2.0] {
+    ///     paths.new_path().extend([Vector::new(x, 0.0, 0.0), Vector::new(x + 1.0, 0.0, 0.0)]);
+    /// }
+    ///
+    /// let jumps = |paths: &Paths<Vector>| -> f64 {
+    ///     let paths: Vec<&[Vector]> = paths.iter_paths().collect();
+    ///     paths.windows(2).map(|w| w[0].last().unwrap().distance(w[1][0])).sum()
+    /// };
+    ///
+    /// let optimized = paths.optimize_order();
+    /// assert_eq!(optimized.len(), paths.len());
+    /// assert!(jumps(&optimized) < jumps(&paths));
+    /// ```
+    pub fn optimize_order(&self) -> Self {
+        let endpoints: Vec<(Vector, Vector)> = self
+            .iter_paths()
+            .map(|path| {
+                let [first, last] = [path.first(), path.last()]
+                    .map(|v| v.map_or_else(Vector::default, |v| Vector::new(v.x, v.y, 0.0)));
+                (first, last)
+            })
+            .collect();
+
+        let mut result = Paths::with_capacity(self.total_len(), self.len());
+        let mut visited = vec![false; endpoints.len()];
+        let mut position = Vector::default();
+
+        for _ in 0..endpoints.len() {
+            let mut best = (f64::INFINITY, 0, false);
+            for (id, (first, last)) in endpoints.iter().enumerate() {
+                if visited[id] {
+                    continue;
+                }
+                for (is_reversed, v) in [(false, first), (true, last)] {
+                    let d = position.distance_squared(*v);
+                    if d < best.0 {
+                        best = (d, id, is_reversed);
+                    }
+                }
+            }
+
+            let (_, id, is_reversed) = best;
+            visited[id] = true;
+            let path = &self[id];
+            if is_reversed {
+                result.new_path().extend(path.iter().rev().copied());
+                position = endpoints[id].0;
+            } else {
+                result.new_path().extend_from_slice(path);
+                position = endpoints[id].1;
+            }
+        }
+
+        result
+    }
+
+    /// Returns the length of all paths in projected x/y, the distance the pen
+    /// travels while drawing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Paths, Vector};
+    ///
+    /// // Two unit squares, 3 apart
+    /// let mut paths = Paths::new();
+    /// for x in [0.0, 4.0] {
+    ///     let square = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)];
+    ///     paths.new_path().extend(square.map(|(dx, y)| Vector::new(x + dx, y, 0.0)));
+    /// }
+    ///
+    /// assert_eq!(paths.num_segments(), 8);
+    /// assert!((paths.total_length() - 8.0).abs() < 1e-9);
+    /// assert!((paths.pen_up_length() - 4.0).abs() < 1e-9);
+    /// ```
+    pub fn total_length(&self) -> f64 {
+        self.iter_paths()
+            .map(|path| {
+                path.windows(2)
+                    .map(|w| distance_2d(w[0], w[1]))
+                    .sum::<f64>()
+            })
+            .sum()
+    }
+
+    /// Returns the distance in projected x/y between the end of each path and
+    /// the start of the next one, the distance the pen travels lifted.
+    ///
+    /// The paths are taken in their current order, see
+    /// [`optimize_order`](Paths::optimize_order) to reduce it.
+    pub fn pen_up_length(&self) -> f64 {
+        let paths: Vec<&[Vector]> = self.iter_paths().collect();
+        paths
+            .windows(2)
+            .map(|w| distance_2d(w[0][w[0].len() - 1], w[1][0]))
+            .sum()
+    }
+
     /// Converts the paths to an SVG string.
     ///
     /// # Arguments
     ///
     /// * `width` - The SVG width
     /// * `height` - The SVG height
+    ///
+    /// See [`to_svg_with`](Paths::to_svg_with) for physical units and stroke width.
     pub fn to_svg(&self, width: f64, height: f64) -> String {
-        let mut lines = Vec::new();
-        lines.push(format!(
-            "<svg width=\"{}\" height=\"{}\" version=\"1.1\" baseProfile=\"full\" xmlns=\"http://www.w3.org/2000/svg\">",
-            width, height
-        ));
-        lines.push(format!(
-            "<g transform=\"translate(0,{}) scale(1,-1)\">",
-            height
-        ));
-        for path in self.iter_paths() {
-            lines.push(path_to_svg(path));
-        }
-        lines.push("</g></svg>".to_string());
-        lines.join("\n")
+        self.to_svg_with(width, height).call()
     }
 
     /// Writes the paths to an SVG file.
     ///
+    /// See [`write_to_svg_with`](Paths::write_to_svg_with) for physical units
+    /// and stroke width.
+    ///
     /// # Example
     ///
     /// ```
@@ -319,13 +1010,140 @@ impl Paths<Vector> {
     /// paths.write_to_svg("output.svg", 1024.0, 1024.0).unwrap();
     /// ```
     pub fn write_to_svg(&self, path: &str, width: f64, height: f64) -> std::io::Result<()> {
-        let svg = self.to_svg(width, height);
-        std::fs::write(path, svg)
+        self.write_to_svg_with(path, width, height).call()
+    }
+
+    /// Converts the paths to a DXF (R12) string.
+    ///
+    /// Each path with at least two points is emitted as a `POLYLINE` entity
+    /// in the `ENTITIES` section, using the projected x/y coordinates
+    /// (the same ones used by [`to_svg`](Paths::to_svg)). Empty and
+    /// single-point paths are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Paths, Vector};
+    ///
+    /// let mut paths = Paths::new();
+    /// paths.new_path().extend([Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 1.0, 0.0)]);
+    /// paths.new_path().push(Vector::new(2.0, 2.0, 0.0));
+    ///
+    /// let dxf = paths.to_dxf();
+    /// assert_eq!(dxf.matches("POLYLINE").count(), 1);
+    /// assert!(dxf.ends_with("EOF\n"));
+    /// ```
+    pub fn to_dxf(&self) -> String {
+        let mut lines: Vec<String> = Vec::new();
+        let mut group = |code: i32, value: &str| {
+            lines.push(code.to_string());
+            lines.push(value.to_string());
+        };
+
+        group(0, "SECTION");
+        group(2, "HEADER");
+        group(9, "$ACADVER");
+        group(1, "AC1009");
+        group(0, "ENDSEC");
+
+        group(0, "SECTION");
+        group(2, "ENTITIES");
+        for path in self.iter_paths().filter(|path| path.len() >= 2) {
+            group(0, "POLYLINE");
+            group(8, "0");
+            group(66, "1");
+            group(10, "0.0");
+            group(20, "0.0");
+            group(30, "0.0");
+            group(70, "0");
+            for v in path {
+                group(0, "VERTEX");
+                group(8, "0");
+                group(10, &v.x.to_string());
+                group(20, &v.y.to_string());
+                group(30, "0.0");
+            }
+            group(0, "SEQEND");
+            group(8, "0");
+        }
+        group(0, "ENDSEC");
+        group(0, "EOF");
+
+        let mut dxf = lines.join("\n");
+        dxf.push('\n');
+        dxf
+    }
+
+    /// Writes the paths to a DXF (R12) file.
+    ///
+    /// See [`to_dxf`](Paths::to_dxf) for details on the output.
+    pub fn write_to_dxf(&self, path: &str) -> std::io::Result<()> {
+        let dxf = self.to_dxf();
+        std::fs::write(path, dxf)
+    }
+
+    /// Converts the paths to an Encapsulated PostScript (EPS) string.
+    ///
+    /// The `%%BoundingBox` is `0 0 width height` and each path with at least
+    /// two points is stroked with `moveto`/`lineto`, using the projected x/y
+    /// coordinates directly since PostScript, like the rendered paths, has its
+    /// origin at the bottom left. Empty and single-point paths are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Paths, Vector};
+    ///
+    /// let mut paths = Paths::new();
+    /// paths.new_path().extend([Vector::new(0.0, 0.0, 0.0), Vector::new(10.0, 20.0, 0.0)]);
+    /// paths.new_path().push(Vector::new(2.0, 2.0, 0.0));
+    ///
+    /// let eps = paths.to_eps(100.0, 50.0);
+    /// assert!(eps.starts_with("%!PS-Adobe-3.0 EPSF-3.0\n%%BoundingBox: 0 0 100 50\n"));
+    /// assert!(eps.contains("newpath\n0 0 moveto\n10 20 lineto\nstroke\n"));
+    /// assert_eq!(eps.matches("newpath").count(), 1);
+    /// ```
+    pub fn to_eps(&self, width: f64, height: f64) -> String {
+        let mut lines = vec![
+            "%!PS-Adobe-3.0 EPSF-3.0".to_string(),
+            format!(
+                "%%BoundingBox: 0 0 {} {}",
+                width.ceil() as i64,
+                height.ceil() as i64
+            ),
+            format!("%%HiResBoundingBox: 0 0 {} {}", width, height),
+            "%%EndComments".to_string(),
+            "1 setlinejoin 1 setlinecap".to_string(),
+        ];
+        for path in self.iter_paths().filter(|path| path.len() >= 2) {
+            lines.push("newpath".to_string());
+            lines.push(format!("{} {} moveto", path[0].x, path[0].y));
+            for v in &path[1..] {
+                lines.push(format!("{} {} lineto", v.x, v.y));
+            }
+            lines.push("stroke".to_string());
+        }
+        lines.push("showpage".to_string());
+        lines.push("%%EOF".to_string());
+
+        let mut eps = lines.join("\n");
+        eps.push('\n');
+        eps
+    }
+
+    /// Writes the paths to an Encapsulated PostScript (EPS) file.
+    ///
+    /// See [`to_eps`](Paths::to_eps) for details on the output.
+    pub fn write_to_eps(&self, path: &str, width: f64, height: f64) -> std::io::Result<()> {
+        let eps = self.to_eps(width, height);
+        std::fs::write(path, eps)
     }
 
     /// Writes the paths to a PNG image file.
     ///
-    /// Renders the paths as black lines on a white background.
+    /// Renders the paths as black lines, 2.5 pixels wide, on a white
+    /// background. See [`write_to_png_with`](Paths::write_to_png_with) to
+    /// change the colors or the line width.
     ///
     /// # Example
     ///
@@ -344,8 +1162,7 @@ impl Paths<Vector> {
         width: f64,
         height: f64,
     ) -> Result<(), image::ImageError> {
-        let img = self.to_image(width, height).linewidth(2.5).call();
-        img.save(path)
+        self.write_to_png_with(path, width, height).call()
     }
 
     /// Writes the paths to a text file.
@@ -362,6 +1179,82 @@ impl Paths<Vector> {
         }
         Ok(())
     }
+
+    /// Reads paths from a text file written by [`write_to_txt`](Paths::write_to_txt).
+    ///
+    /// Blank lines and trailing semicolons are ignored, the z coordinate of
+    /// every point is `0`. A malformed point is reported as an
+    /// [`InvalidData`](std::io::ErrorKind::InvalidData) error naming its line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Paths, Vector};
+    ///
+    /// let mut paths = Paths::new();
+    /// paths.new_path().extend([Vector::new(0.0, 0.0, 0.0), Vector::new(1.5, -2.0, 0.0)]);
+    /// paths.new_path().extend([Vector::new(3.0, 4.0, 0.0), Vector::new(5.0, 6.0, 0.0)]);
+    ///
+    /// let file = std::env::temp_dir().join("larnt_paths.txt");
+    /// let file = file.to_str().unwrap();
+    /// paths.write_to_txt(file).unwrap();
+    /// let restored = Paths::read_from_txt(file).unwrap();
+    /// assert!(paths.iter_paths().eq(restored.iter_paths()));
+    ///
+    /// std::fs::write(file, "0,0;1,1;\n\n2,2;oops\n").unwrap();
+    /// let err = Paths::read_from_txt(file).unwrap_err();
+    /// assert!(err.to_string().contains("line 3"));
+    /// ```
+    pub fn read_from_txt(path: &str) -> std::io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        let mut paths = Paths::new();
+        for (i, line) in text.lines().enumerate() {
+            let invalid = |point: &str| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("line {}: invalid point `{}`", i + 1, point),
+                )
+            };
+            let mut new_path = paths.new_path();
+            for point in line.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+                let (x, y) = point.split_once(',').ok_or_else(|| invalid(point))?;
+                let x = x.trim().parse().map_err(|_| invalid(point))?;
+                let y = y.trim().parse().map_err(|_| invalid(point))?;
+                new_path.push(Vector::new(x, y, 0.0));
+            }
+        }
+        Ok(paths)
+    }
+
+    /// Converts the paths to a JSON string.
+    ///
+    /// The output is a list of paths, each a list of `[x, y, z]` points, and
+    /// keeps every coordinate, unlike [`write_to_txt`](Paths::write_to_txt).
+    /// Non-finite coordinates are written as `null` and cannot be read back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Cube, Paths, Vector, render};
+    ///
+    /// let cube = Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build();
+    /// let paths = render(vec![cube]).eye(Vector::new(4.0, 3.0, 2.0)).call();
+    ///
+    /// let json = paths.to_json();
+    /// let restored = Paths::from_json(&json).unwrap();
+    /// assert_eq!(restored.len(), paths.len());
+    /// assert!(paths.iter_paths().eq(restored.iter_paths()));
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("paths are always serializable")
+    }
+
+    /// Parses paths from the JSON written by [`to_json`](Paths::to_json).
+    #[cfg(feature = "serde")]
+    pub fn from_json(s: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(s)
+    }
 }
 
 impl<T: Copy> Paths<T> {
@@ -379,7 +1272,7 @@ impl<T: Copy> Paths<T> {
         I: IntoIterator<Item = K>,
         FC: FnMut(&T, &T) -> bool,
     {
-        if self.len() == 0 {
+        if self.is_empty() {
             return Paths::new();
         }
 
@@ -520,6 +1413,179 @@ impl<T: Copy> Paths<T> {
     }
 }
 
+/// The shape drawn at the ends of open SVG strokes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+/// The shape drawn at the corners of SVG strokes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineJoin {
+    Miter,
+    Round,
+    Bevel,
+}
+
+/// Stroke attributes for SVG output.
+///
+/// The attributes are written once on the group wrapping all paths, unset
+/// ones are left to the viewer's defaults.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{LineCap, LineJoin, Paths, SvgStyle, Vector};
+///
+/// let mut paths = Paths::new();
+/// paths.new_path().extend([Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 1.0, 0.0)]);
+///
+/// let style = SvgStyle::builder()
+///     .stroke_width(0.3)
+///     .color([0, 0, 255])
+///     .linecap(LineCap::Round)
+///     .linejoin(LineJoin::Round)
+///     .build();
+/// let svg = paths.to_svg_with(10.0, 10.0).style(style).call();
+/// assert!(svg.contains(
+///     "<g transform=\"translate(0,10) scale(1,-1)\" stroke=\"rgb(0,0,255)\" \
+///      stroke-width=\"0.3\" stroke-linecap=\"round\" stroke-linejoin=\"round\">"
+/// ));
+/// assert!(svg.contains("<polyline fill=\"none\""));
+/// ```
+#[derive(Debug, Clone, Default, Builder)]
+pub struct SvgStyle {
+    /// The stroke width, in the coordinates of the paths.
+    pub stroke_width: Option<f64>,
+    /// The stroke color.
+    pub color: Option<[u8; 3]>,
+    /// The shape at the ends of strokes.
+    pub linecap: Option<LineCap>,
+    /// The shape at the corners of strokes.
+    pub linejoin: Option<LineJoin>,
+}
+
+impl SvgStyle {
+    /// Returns the attributes as they are written on the SVG group.
+    fn attributes(&self) -> String {
+        let mut attributes = String::new();
+        if let Some(color) = self.color {
+            attributes += &format!(" stroke=\"{}\"", svg_color(Some(color)));
+        }
+        if let Some(width) = self.stroke_width {
+            attributes += &format!(" stroke-width=\"{}\"", width);
+        }
+        if let Some(linecap) = self.linecap {
+            let linecap = match linecap {
+                LineCap::Butt => "butt",
+                LineCap::Round => "round",
+                LineCap::Square => "square",
+            };
+            attributes += &format!(" stroke-linecap=\"{}\"", linecap);
+        }
+        if let Some(linejoin) = self.linejoin {
+            let linejoin = match linejoin {
+                LineJoin::Miter => "miter",
+                LineJoin::Round => "round",
+                LineJoin::Bevel => "bevel",
+            };
+            attributes += &format!(" stroke-linejoin=\"{}\"", linejoin);
+        }
+        attributes
+    }
+}
+
+/// A collection of paths with an optional stroke color per path.
+///
+/// `ColoredPaths` keeps a color for every path in the underlying [`Paths`],
+/// so that shapes rendered separately can be combined into a single SVG
+/// while remaining distinguishable. Paths without a color are drawn black.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{ColoredPaths, Cube, Sphere, Vector, render};
+///
+/// let eye = Vector::new(4.0, 3.0, 2.0);
+/// let cube = Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build();
+/// let sphere = Sphere::builder(Vector::new(0.0, 0.0, 2.0), 1.0).build();
+///
+/// let mut colored = ColoredPaths::new();
+/// colored.push(render(vec![cube]).eye(eye).call(), Some([0, 0, 255]));
+/// colored.push(render(vec![sphere]).eye(eye).call(), Some([255, 0, 0]));
+///
+/// let svg = colored.to_svg(1024.0, 1024.0);
+/// assert!(svg.contains("stroke=\"rgb(0,0,255)\""));
+/// assert!(svg.contains("stroke=\"rgb(255,0,0)\""));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ColoredPaths {
+    paths: Paths<Vector>,
+    colors: Vec<Option<[u8; 3]>>,
+}
+
+impl ColoredPaths {
+    /// Creates a new empty `ColoredPaths` collection.
+    pub fn new() -> Self {
+        ColoredPaths {
+            paths: Paths::new(),
+            colors: Vec::new(),
+        }
+    }
+
+    /// Appends all paths from `paths`, stroked with `color` (`None` for black).
+    pub fn push(&mut self, paths: Paths<Vector>, color: Option<[u8; 3]>) {
+        self.colors.extend(std::iter::repeat_n(color, paths.len()));
+        self.paths.extend(paths);
+    }
+
+    /// Returns the underlying paths.
+    pub fn paths(&self) -> &Paths<Vector> {
+        &self.paths
+    }
+
+    /// Returns the stroke color of each path.
+    pub fn colors(&self) -> &[Option<[u8; 3]>] {
+        &self.colors
+    }
+
+    /// Converts the paths to an SVG string, using each path's color as its stroke.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The SVG width
+    /// * `height` - The SVG height
+    pub fn to_svg(&self, width: f64, height: f64) -> String {
+        svg_document(
+            width,
+            height,
+            "",
+            None,
+            "",
+            self.paths
+                .iter_paths()
+                .zip(&self.colors)
+                .map(|(path, &color)| path_to_svg(path, Some(&svg_color(color)))),
+        )
+    }
+
+    /// Writes the colored paths to an SVG file.
+    pub fn write_to_svg(&self, path: &str, width: f64, height: f64) -> std::io::Result<()> {
+        let svg = self.to_svg(width, height);
+        std::fs::write(path, svg)
+    }
+}
+
+impl From<Paths<Vector>> for ColoredPaths {
+    fn from(paths: Paths<Vector>) -> Self {
+        let mut colored = ColoredPaths::new();
+        colored.push(paths, None);
+        colored
+    }
+}
+
 pub struct NewPath<'a, T> {
     buffer: &'a mut Vec<T>,
     offsets: &'a mut Vec<usize>,
@@ -641,21 +1707,58 @@ fn draw_line(
             if alpha > 0.0 {
                 let pixel_x = x as u32;
                 let pixel_y = y as u32;
-                let bg_pixel = img.get_pixel(pixel_x, pixel_y);
-                let bg_channels = bg_pixel.channels();
-                let fg_channels = color.channels();
-                let mut new_channels = [0u8; 4];
-
-                for i in 0..4 {
-                    let bg_val = bg_channels[i] as f64;
-                    let fg_val = fg_channels[i] as f64;
-                    new_channels[i] = (bg_val * (1.0 - alpha) + fg_val * alpha) as u8;
-                }
+                let pixel = blend(*img.get_pixel(pixel_x, pixel_y), color, alpha);
+                img.put_pixel(pixel_x, pixel_y, pixel);
+            }
+        }
+    }
+}
 
-                img.put_pixel(pixel_x, pixel_y, *Rgba::from_slice(&new_channels));
+/// Averages each `ss` by `ss` block of `img` into one pixel, weighting the
+/// colors by their alpha.
+#[cfg(feature = "image")]
+fn downsample(img: &ImageBuffer<Rgba<u8>, Vec<u8>>, ss: u32) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
+    ImageBuffer::from_fn(img.width() / ss, img.height() / ss, |x, y| {
+        let mut sum = [0.0; 4];
+        for dy in 0..ss {
+            for dx in 0..ss {
+                let p = img.get_pixel(x * ss + dx, y * ss + dy);
+                let a = p[3] as f64;
+                for i in 0..3 {
+                    sum[i] += p[i] as f64 * a;
+                }
+                sum[3] += a;
             }
         }
+        if sum[3] == 0.0 {
+            return Rgba([0, 0, 0, 0]);
+        }
+        let channel = |i: usize| (sum[i] / sum[3]).round() as u8;
+        let alpha = (sum[3] / (ss * ss) as f64).round() as u8;
+        Rgba([channel(0), channel(1), channel(2), alpha])
+    })
+}
+
+/// Composites `src`, scaled by the pixel `coverage`, over `dst` with
+/// straight (non-premultiplied) alpha.
+#[cfg(feature = "image")]
+fn blend(dst: Rgba<u8>, src: Rgba<u8>, coverage: f64) -> Rgba<u8> {
+    let src_a = src[3] as f64 / 255.0 * coverage;
+    let dst_a = dst[3] as f64 / 255.0;
+    let out_a = src_a + dst_a * (1.0 - src_a);
+    if out_a <= 0.0 {
+        return Rgba([0, 0, 0, 0]);
     }
+    let channel = |i: usize| {
+        let c = (src[i] as f64 * src_a + dst[i] as f64 * dst_a * (1.0 - src_a)) / out_a;
+        c.round().clamp(0.0, 255.0) as u8
+    };
+    Rgba([
+        channel(0),
+        channel(1),
+        channel(2),
+        (out_a * 255.0).round() as u8,
+    ])
 }
 
 fn path_chop(path: &[Vector], step: f64, new_path: &mut NewPath<Vector>) {
@@ -711,6 +1814,7 @@ fn path_chop_adaptive(
                     || sa.y > height && sb.y > height)
                     || sa.distance_squared(sb) < step_sq
                     || a.distance_squared(b) < crate::common::EPS
+                    || !a.distance_squared(b).is_finite()
             },
             &mut |(x, _)| new_path.push(x),
         );
@@ -725,20 +1829,22 @@ fn path_chop_adaptive(
 ///
 /// The arc is defined by a center point `c` and two orthogonal vectors `u` and `v` that
 /// define the plane of the arc. The radius `r` determines how far from the center the
-/// arc points are. The `screen_mat` is used to project the 3D points onto the screen
-/// for distance calculations. The `step_sq` parameter controls how closely the subdivided points
-/// approximate the arc on screen, with smaller values resulting in more points for a smoother arc.
+/// arc points are. The `screen_mat` of `args` is used to project the 3D points onto the screen
+/// for distance calculations. Its `step` controls how closely the subdivided points
+/// approximate the arc on screen, with smaller values resulting in more points for a smoother arc,
+/// and its `min_arc_angle` stops the subdivision at coarse pieces regardless of the step.
 fn recursive_arc_subdivide(
     alpha: f64,
     beta: f64,
     r: f64,
     cuv: &(Vector, Vector, Vector),
-    screen_mat: &Matrix,
-    step_sq: f64,
+    args: &RenderArgs,
     collector: &mut impl FnMut(f64),
 ) {
+    let step_sq = args.step.powi(2);
+    let min_theta = args.min_arc_angle.to_radians() / 2.0;
     let screen_view = |x: f64| {
-        screen_mat.mul_position_w(
+        args.screen_mat.mul_position_w(
             (cuv.0)
                 .add((cuv.1).mul_scalar(x.cos() * r))
                 .add((cuv.2).mul_scalar(x.sin() * r)),
@@ -753,8 +1859,10 @@ fn recursive_arc_subdivide(
         },
         &|(alpha, sa), (beta, sb)| {
             let theta = (beta - alpha) / 2.0;
-            theta < PI / 180.0
+            theta < min_theta
                 || sa.distance_squared(sb) * theta / theta.sin() < step_sq && theta < PI / 3.0
+                || !theta.is_finite()
+                || !sa.distance_squared(sb).is_finite()
         },
         &mut |(x, _)| collector(x),
     );
@@ -768,11 +1876,10 @@ pub fn adaptive_arc(
     beta: f64,
     r: f64,
     cuv: &(Vector, Vector, Vector),
-    screen_mat: &Matrix,
-    step_sq: f64,
+    args: &RenderArgs,
     new_path: &mut NewPath<Vector>,
 ) {
-    recursive_arc_subdivide(alpha, beta, r, cuv, screen_mat, step_sq, &mut |x| {
+    recursive_arc_subdivide(alpha, beta, r, cuv, args, &mut |x| {
         new_path.push(Vector::new(x, 0., 0.))
     });
     let (c, u, v) = cuv;
@@ -802,11 +1909,10 @@ pub fn adaptive_arc_inner(
     beta: f64,
     r: f64,
     cuv: &(Vector, Vector, Vector),
-    screen_mat: &Matrix,
-    step_sq: f64,
+    args: &RenderArgs,
     new_path: &mut NewPath<Vector>,
 ) {
-    recursive_arc_subdivide(alpha, beta, r, cuv, screen_mat, step_sq, &mut |x| {
+    recursive_arc_subdivide(alpha, beta, r, cuv, args, &mut |x| {
         new_path.push(Vector::new(x, 0., 0.))
     });
     let (c, u, v) = cuv;
@@ -818,23 +1924,45 @@ pub fn adaptive_arc_inner(
     });
 }
 
+/// The deepest [`recursive_subdivide`] goes, splitting a segment into at most
+/// `2^MAX_SUBDIVIDE_DEPTH` pieces, about a million.
+pub const MAX_SUBDIVIDE_DEPTH: u32 = 20;
+
+/// Splits the segment `ab` in two with `divider` until `terminator` accepts
+/// each piece, passing the end of every accepted piece to `collector` in
+/// order (the start `a` is not collected).
+///
+/// Recursion stops after [`MAX_SUBDIVIDE_DEPTH`] levels even if `terminator`
+/// never accepts, so degenerate input cannot overflow the stack. A terminator
+/// that keeps rejecting every piece (e.g. because of NaN) still emits all
+/// `2^MAX_SUBDIVIDE_DEPTH` points, so it should accept non-finite input itself.
 pub fn recursive_subdivide<T: Copy>(
     ab: (T, T),
     divider: &impl Fn(T, T) -> T,
     terminator: &impl Fn(T, T) -> bool,
     collector: &mut impl FnMut(T),
+) {
+    subdivide_to_depth(ab, divider, terminator, collector, MAX_SUBDIVIDE_DEPTH);
+}
+
+fn subdivide_to_depth<T: Copy>(
+    ab: (T, T),
+    divider: &impl Fn(T, T) -> T,
+    terminator: &impl Fn(T, T) -> bool,
+    collector: &mut impl FnMut(T),
+    depth: u32,
 ) {
     let (a, b) = ab;
-    if terminator(a, b) {
+    if depth == 0 || terminator(a, b) {
         collector(b);
     } else {
         let mid = divider(a, b);
-        recursive_subdivide((a, mid), divider, terminator, collector);
-        recursive_subdivide((mid, b), divider, terminator, collector);
+        subdivide_to_depth((a, mid), divider, terminator, collector, depth - 1);
+        subdivide_to_depth((mid, b), divider, terminator, collector, depth - 1);
     }
 }
 
-fn path_filter<F: Filter>(path: &[Vector], f: &F, result: &mut Paths<Vector>) {
+pub(crate) fn path_filter<F: Filter>(path: &[Vector], f: &F, result: &mut Paths<Vector>) {
     let mut current_path = result.new_path();
 
     for v in path {
@@ -847,6 +1975,68 @@ fn path_filter<F: Filter>(path: &[Vector], f: &F, result: &mut Paths<Vector>) {
     }
 }
 
+fn path_clip_rect(path: &[Vector], width: f64, height: f64, result: &mut Paths<Vector>) {
+    let inside = |v: &Vector| v.x >= 0.0 && v.x <= width && v.y >= 0.0 && v.y <= height;
+    if let [v] = path {
+        if inside(v) {
+            result.new_path().push(*v);
+        }
+        return;
+    }
+
+    let mut current = result.new_path();
+    for w in path.windows(2) {
+        let (a, b) = (w[0], w[1]);
+        let Some((t0, t1)) = clip_segment(a, b, width, height) else {
+            drop(current);
+            current = result.new_path();
+            continue;
+        };
+        if t0 > 0.0 || current.is_empty() {
+            drop(current);
+            current = result.new_path();
+            current.push(a.add(b.sub(a).mul_scalar(t0)));
+        }
+        current.push(if t1 < 1.0 {
+            a.add(b.sub(a).mul_scalar(t1))
+        } else {
+            b
+        });
+        if t1 < 1.0 {
+            drop(current);
+            current = result.new_path();
+        }
+    }
+}
+
+/// Returns the parameters `t0 <= t1` in `[0, 1]` between which the segment
+/// from `a` to `b` lies in `[0, width] x [0, height]`, using Liang-Barsky.
+fn clip_segment(a: Vector, b: Vector, width: f64, height: f64) -> Option<(f64, f64)> {
+    let d = b.sub(a);
+    let (mut t0, mut t1) = (0.0_f64, 1.0_f64);
+    for (p, q) in [
+        (-d.x, a.x),
+        (d.x, width - a.x),
+        (-d.y, a.y),
+        (d.y, height - a.y),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            t0 = t0.max(q / p);
+        } else {
+            t1 = t1.min(q / p);
+        }
+    }
+    (t0 <= t1).then_some((t0, t1))
+}
+
+fn distance_2d(a: Vector, b: Vector) -> f64 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
 fn path_simplify(path: &[Vector], threshold: f64, new_path: &mut NewPath<Vector>) {
     if path.len() < 3 {
         new_path.extend_from_slice(path);
@@ -874,13 +2064,45 @@ fn path_simplify(path: &[Vector], threshold: f64, new_path: &mut NewPath<Vector>
     }
 }
 
-fn path_to_svg(path: &[Vector]) -> String {
+fn svg_document(
+    width: f64,
+    height: f64,
+    units: &str,
+    view_box: Option<(f64, f64, f64, f64)>,
+    group_attributes: &str,
+    polylines: impl Iterator<Item = String>,
+) -> String {
+    let view_box = view_box
+        .map(|(x, y, w, h)| format!(" viewBox=\"{} {} {} {}\"", x, y, w, h))
+        .unwrap_or_default();
+    let mut lines = Vec::new();
+    lines.push(format!(
+        "<svg width=\"{}{units}\" height=\"{}{units}\"{view_box} version=\"1.1\" baseProfile=\"full\" xmlns=\"http://www.w3.org/2000/svg\">",
+        width, height
+    ));
+    lines.push(format!(
+        "<g transform=\"translate(0,{}) scale(1,-1)\"{group_attributes}>",
+        height
+    ));
+    lines.extend(polylines);
+    lines.push("</g></svg>".to_string());
+    lines.join("\n")
+}
+
+fn svg_color(color: Option<[u8; 3]>) -> String {
+    match color {
+        Some([r, g, b]) => format!("rgb({},{},{})", r, g, b),
+        None => "black".to_string(),
+    }
+}
+
+fn path_to_svg(path: &[Vector], stroke: Option<&str>) -> String {
     let coords: Vec<String> = path.iter().map(|v| format!("{},{}", v.x, v.y)).collect();
     let points = coords.join(" ");
-    format!(
-        "<polyline stroke=\"black\" fill=\"none\" points=\"{}\" />",
-        points
-    )
+    let stroke = stroke
+        .map(|stroke| format!(" stroke=\"{}\"", stroke))
+        .unwrap_or_default();
+    format!("<polyline{} fill=\"none\" points=\"{}\" />", stroke, points)
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -33,19 +33,82 @@ impl Plane {
         Some(v0.add(u.mul_scalar(t)))
     }
 
+    /// Returns the segment where the plane cuts the triangle, if any.
+    ///
+    /// Vertices exactly on the plane count as lying on the side `normal`
+    /// points to. A plane through a vertex or along an edge of a mesh thus
+    /// cuts each piece of the contour once, from the faces reaching behind
+    /// the plane, and a triangle only touching the plane gives `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Plane, Triangle, Vector};
+    ///
+    /// let plane = Plane::new(Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+    ///
+    /// // Through the top vertex and the middle of the opposite edge
+    /// let t = Triangle::new(Vector::new(-1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+    /// let (a, b) = plane.intersect_triangle(&t).unwrap();
+    /// assert!((a.distance(b) - 1.0).abs() < 1e-9);
+    ///
+    /// // Touching at a single vertex
+    /// let t = Triangle::new(Vector::new(0.0, 1.0, 0.0), Vector::new(1.0, 0.0, 0.0), Vector::new(2.0, 1.0, 0.0));
+    /// assert!(plane.intersect_triangle(&t).is_none());
+    /// ```
     pub fn intersect_triangle(&self, t: &Triangle) -> Option<(Vector, Vector)> {
-        let v1 = self.intersect_segment(t.v1, t.v2);
-        let v2 = self.intersect_segment(t.v2, t.v3);
-        let v3 = self.intersect_segment(t.v3, t.v1);
-
-        match (v1, v2, v3) {
-            (Some(v1), Some(v2), _) => Some((v1, v2)),
-            (Some(v1), _, Some(v3)) => Some((v1, v3)),
-            (_, Some(v2), Some(v3)) => Some((v2, v3)),
-            _ => None,
-        }
+        let vertices = [t.v1, t.v2, t.v3];
+        let distances = vertices.map(|v| self.normal.dot(v.sub(self.point)));
+
+        let mut hits = (0..3).filter_map(|i| {
+            let j = (i + 1) % 3;
+            // Interpolate from the vertex behind the plane, so the faces
+            // sharing an edge compute exactly the same point
+            let (below, above) = match (distances[i] < 0.0, distances[j] < 0.0) {
+                (true, false) => (i, j),
+                (false, true) => (j, i),
+                _ => return None,
+            };
+            let t = distances[below] / (distances[below] - distances[above]);
+            Some(vertices[below].add(vertices[above].sub(vertices[below]).mul_scalar(t)))
+        });
+
+        let (a, b) = (hits.next()?, hits.next()?);
+        (a != b).then_some((a, b))
     }
 
+    /// Slices the mesh, returning one two-point path per cut triangle.
+    ///
+    /// The plane may have any orientation, and `normal` need not be normalized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Mesh, Plane, Vector};
+    ///
+    /// // A closed unit cube of 12 triangles
+    /// let cube = Mesh::rounded_box(Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 1.0, 1.0), 0.0, 1);
+    ///
+    /// // The area enclosed by the closed contours, seen along `normal`
+    /// let area = |plane: &Plane| {
+    ///     let contours = plane.intersect_mesh_contours(&cube);
+    ///     assert!(contours.iter_paths().all(|c| c[0].distance(c[c.len() - 1]) < 1e-9));
+    ///     let twice = contours.iter_paths().fold(Vector::default(), |sum, c| {
+    ///         c.windows(2).fold(sum, |sum, w| sum.add(w[0].cross(w[1])))
+    ///     });
+    ///     twice.dot(plane.normal.normalize()) / 2.0
+    /// };
+    ///
+    /// // Through three corners: an equilateral triangle with sides of sqrt(2)
+    /// let diagonal = Vector::new(1.0, 1.0, 1.0);
+    /// let corners = Plane::new(Vector::new(1.0, 0.0, 0.0), diagonal);
+    /// assert!((area(&corners) - 3.0_f64.sqrt() / 2.0).abs() < 1e-9);
+    /// assert!(corners.intersect_mesh(&cube).iter_paths().all(|s| s[0].distance(s[1]) > 1e-9));
+    ///
+    /// // Through the center: a regular hexagon with sides of sqrt(2) / 2
+    /// let center = Plane::new(Vector::new(0.5, 0.5, 0.5), diagonal);
+    /// assert!((area(&center) - 3.0 * 3.0_f64.sqrt() / 4.0).abs() < 1e-9);
+    /// ```
     pub fn intersect_mesh(&self, m: &impl TriangleMesh) -> Paths<Vector> {
         let mut result = Paths::new();
         for t in m.triangles() {
@@ -55,4 +118,83 @@ impl Plane {
         }
         result
     }
+
+    /// Slices the mesh like [`intersect_mesh`](Plane::intersect_mesh), but
+    /// stitches the segments into continuous polylines.
+    ///
+    /// Segments are linked where their endpoints coincide within
+    /// [`EPS`](crate::common::EPS). The contours are oriented by the faces they
+    /// cut: seen from the side `normal` points to, the outline of a closed mesh
+    /// runs counterclockwise and the outline of a hole clockwise. A closed
+    /// contour ends with its first point, open ones (e.g. where the mesh has a
+    /// boundary) do not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Mesh, Plane, Vector};
+    ///
+    /// let mesh = Mesh::octahedron(1.0);
+    /// let plane = Plane::new(Vector::new(0.0, 0.0, 0.5), Vector::new(0.0, 0.0, 1.0));
+    ///
+    /// assert_eq!(plane.intersect_mesh(&mesh).len(), 4);
+    /// let contours = plane.intersect_mesh_contours(&mesh);
+    /// assert_eq!(contours.len(), 1);
+    /// let square = &contours[0];
+    /// assert_eq!(square.len(), 5);
+    /// assert!(square[0].distance(square[4]) < 1e-9);
+    ///
+    /// // Counterclockwise seen from above
+    /// let area: f64 = square.windows(2).map(|w| w[0].x * w[1].y - w[1].x * w[0].y).sum();
+    /// assert!((area / 2.0 - 0.5).abs() < 1e-9);
+    /// ```
+    pub fn intersect_mesh_contours(&self, m: &impl TriangleMesh) -> Paths<Vector> {
+        // Each point carries whether it ends its segment, so segments are
+        // only linked end to start
+        let mut segments: Paths<(Vector, bool)> = Paths::new();
+        for t in m.triangles() {
+            let t = t.as_ref();
+            if let Some((v1, v2)) = self.intersect_triangle(t) {
+                let face_normal = t.v2.sub(t.v1).cross(t.v3.sub(t.v1));
+                let forward = self.normal.cross(face_normal);
+                let (v1, v2) = if v2.sub(v1).dot(forward) < 0.0 {
+                    (v2, v1)
+                } else {
+                    (v1, v2)
+                };
+                if v1.distance_squared(v2) > EPS * EPS {
+                    segments.new_path().extend([(v1, false), (v2, true)]);
+                }
+            }
+        }
+
+        let cell = |(v, _): &(Vector, bool)| {
+            let [x, y, z] = [v.x, v.y, v.z].map(|c| (c / EPS).floor() as i64);
+            (x, y, z)
+        };
+        let contours = segments.splice(
+            cell,
+            |v| {
+                let (x, y, z) = cell(v);
+                (-1..=1).flat_map(move |dx| {
+                    (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (x + dx, y + dy, z + dz)))
+                })
+            },
+            |a, b| a.1 != b.1 && a.0.distance_squared(b.0) < EPS * EPS,
+            true,
+        );
+
+        let mut result = Paths::with_capacity(contours.total_len(), contours.len());
+        for contour in contours.iter_paths() {
+            // Chains walked from their last segment start with an end point
+            if contour[0].1 {
+                result
+                    .new_path()
+                    .extend(contour.iter().rev().map(|(v, _)| *v));
+            } else {
+                result.new_path().extend(contour.iter().map(|(v, _)| *v));
+            }
+        }
+        result
+    }
 }
@@ -0,0 +1,276 @@
+//! PLY file loader.
+//!
+//! This module provides functionality to load 3D models from Stanford PLY
+//! files, in either the ASCII or the binary little-endian format.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use larnt::{load_ply, render};
+//!
+//! let mesh = load_ply("model.ply").expect("Failed to load PLY");
+//! let paths = render(vec![mesh]).eye(larnt::Vector::new(4.0, 3.0, 2.0)).call();
+//! ```
+
+use crate::mesh::Mesh;
+use crate::vector::Vector;
+use std::io::{Error, ErrorKind, Result};
+
+fn invalid(message: impl Into<String>) -> Error {
+    Error::new(ErrorKind::InvalidData, message.into())
+}
+
+/// A scalar type of a PLY property.
+#[derive(Debug, Clone, Copy)]
+enum Scalar {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl Scalar {
+    fn parse(name: &str) -> Result<Self> {
+        Ok(match name {
+            "char" | "int8" => Scalar::I8,
+            "uchar" | "uint8" => Scalar::U8,
+            "short" | "int16" => Scalar::I16,
+            "ushort" | "uint16" => Scalar::U16,
+            "int" | "int32" => Scalar::I32,
+            "uint" | "uint32" => Scalar::U32,
+            "float" | "float32" => Scalar::F32,
+            "double" | "float64" => Scalar::F64,
+            _ => return Err(invalid(format!("unknown PLY type `{name}`"))),
+        })
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Scalar::I8 | Scalar::U8 => 1,
+            Scalar::I16 | Scalar::U16 => 2,
+            Scalar::I32 | Scalar::U32 | Scalar::F32 => 4,
+            Scalar::F64 => 8,
+        }
+    }
+
+    fn read_le(self, b: &[u8]) -> f64 {
+        match self {
+            Scalar::I8 => b[0] as i8 as f64,
+            Scalar::U8 => b[0] as f64,
+            Scalar::I16 => i16::from_le_bytes([b[0], b[1]]) as f64,
+            Scalar::U16 => u16::from_le_bytes([b[0], b[1]]) as f64,
+            Scalar::I32 => i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+            Scalar::U32 => u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+            Scalar::F32 => f32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+            Scalar::F64 => f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]),
+        }
+    }
+}
+
+/// A property declared in the PLY header.
+#[derive(Debug)]
+enum Property {
+    Scalar(Scalar, String),
+    List(Scalar, Scalar, String),
+}
+
+/// An element declared in the PLY header.
+#[derive(Debug)]
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+/// The body of a PLY file, read value by value.
+enum Body<'a> {
+    Ascii(std::str::SplitAsciiWhitespace<'a>),
+    Binary(&'a [u8]),
+}
+
+impl Body<'_> {
+    fn next(&mut self, ty: Scalar) -> Result<f64> {
+        match self {
+            Body::Ascii(tokens) => tokens
+                .next()
+                .ok_or_else(|| invalid("unexpected end of PLY data"))?
+                .parse()
+                .map_err(|_| invalid("invalid number in PLY data")),
+            Body::Binary(bytes) => {
+                if bytes.len() < ty.size() {
+                    return Err(invalid("unexpected end of PLY data"));
+                }
+                let (value, rest) = bytes.split_at(ty.size());
+                *bytes = rest;
+                Ok(ty.read_le(value))
+            }
+        }
+    }
+}
+
+/// Loads a triangle mesh from a PLY file.
+///
+/// Both the `ascii` and `binary_little_endian` formats are supported. The
+/// `x`, `y` and `z` properties of the `vertex` element and the
+/// `vertex_indices` list of the `face` element are read, polygonal faces are
+/// triangulated as a fan. Any other element or property is skipped.
+///
+/// # Arguments
+///
+/// * `path` - Path to the PLY file
+///
+/// # Returns
+///
+/// A [`Mesh`] containing the loaded triangles.
+///
+/// # Example
+///
+/// ```
+/// use larnt::load_ply;
+///
+/// let ply = "ply
+/// format ascii 1.0
+/// comment a tetrahedron with colored vertices
+/// element vertex 4
+/// property float x
+/// property float y
+/// property float z
+/// property uchar red
+/// property uchar green
+/// property uchar blue
+/// element face 4
+/// property list uchar int vertex_indices
+/// end_header
+/// 0 0 0 255 0 0
+/// 1 0 0 0 255 0
+/// 0 1 0 0 0 255
+/// 0 0 1 255 255 255
+/// 3 0 2 1
+/// 3 0 1 3
+/// 3 0 3 2
+/// 3 1 2 3
+/// ";
+/// let path = std::env::temp_dir().join("larnt_tetrahedron.ply");
+/// std::fs::write(&path, ply).unwrap();
+///
+/// let mesh = load_ply(path.to_str().unwrap()).expect("Failed to load PLY");
+/// assert_eq!(mesh.vertices.len(), 4);
+/// assert_eq!(mesh.triangles, vec![0, 2, 1, 0, 1, 3, 0, 3, 2, 1, 2, 3]);
+/// assert_eq!(mesh.vertices[3].z, 1.0);
+/// ```
+pub fn load_ply(path: &str) -> Result<Mesh> {
+    let data = std::fs::read(path)?;
+
+    let header_end = data
+        .windows(b"end_header".len())
+        .position(|w| w == b"end_header")
+        .ok_or_else(|| invalid("missing PLY end_header"))?;
+    let body_start = data[header_end..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(data.len(), |i| header_end + i + 1);
+    let header = std::str::from_utf8(&data[..header_end])
+        .map_err(|_| invalid("PLY header is not valid UTF-8"))?;
+
+    let mut lines = header.lines();
+    if lines.next().map(str::trim) != Some("ply") {
+        return Err(invalid("missing PLY magic number"));
+    }
+
+    let mut binary = None;
+    let mut elements: Vec<Element> = Vec::new();
+    for line in lines {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        match fields.as_slice() {
+            ["format", "ascii", ..] => binary = Some(false),
+            ["format", "binary_little_endian", ..] => binary = Some(true),
+            ["format", format, ..] => {
+                return Err(invalid(format!("unsupported PLY format `{format}`")));
+            }
+            ["element", name, count] => elements.push(Element {
+                name: name.to_string(),
+                count: count
+                    .parse()
+                    .map_err(|_| invalid("invalid PLY element count"))?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", count, item, name] => elements
+                .last_mut()
+                .ok_or_else(|| invalid("PLY property outside of an element"))?
+                .properties
+                .push(Property::List(
+                    Scalar::parse(count)?,
+                    Scalar::parse(item)?,
+                    name.to_string(),
+                )),
+            ["property", ty, name] => elements
+                .last_mut()
+                .ok_or_else(|| invalid("PLY property outside of an element"))?
+                .properties
+                .push(Property::Scalar(Scalar::parse(ty)?, name.to_string())),
+            _ => {}
+        }
+    }
+
+    let mut body = match binary {
+        Some(true) => Body::Binary(&data[body_start..]),
+        Some(false) => Body::Ascii(
+            std::str::from_utf8(&data[body_start..])
+                .map_err(|_| invalid("PLY data is not valid UTF-8"))?
+                .split_ascii_whitespace(),
+        ),
+        None => return Err(invalid("missing PLY format")),
+    };
+
+    let mut vertices = Vec::new();
+    let mut triangles = Vec::new();
+    for element in &elements {
+        for _ in 0..element.count {
+            let mut position = [0.0; 3];
+            let mut face = Vec::new();
+            for property in &element.properties {
+                match property {
+                    Property::Scalar(ty, name) => {
+                        let value = body.next(*ty)?;
+                        match name.as_str() {
+                            "x" => position[0] = value,
+                            "y" => position[1] = value,
+                            "z" => position[2] = value,
+                            _ => {}
+                        }
+                    }
+                    Property::List(count, item, name) => {
+                        let count = body.next(*count)? as usize;
+                        let is_face = name == "vertex_indices" || name == "vertex_index";
+                        for _ in 0..count {
+                            let value = body.next(*item)?;
+                            if is_face {
+                                face.push(value as usize);
+                            }
+                        }
+                    }
+                }
+            }
+
+            match element.name.as_str() {
+                "vertex" => vertices.push(Vector::new(position[0], position[1], position[2])),
+                "face" => {
+                    for i in 1..face.len().saturating_sub(1) {
+                        triangles.extend([face[0], face[i], face[i + 1]]);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if let Some(&i) = triangles.iter().find(|&&i| i >= vertices.len()) {
+        return Err(invalid(format!("PLY face references missing vertex {i}")));
+    }
+
+    Ok(Mesh::builder(vertices, triangles).build())
+}
@@ -0,0 +1,116 @@
+//! Point cloud primitive.
+//!
+//! This module provides the [`PointCloud`] shape, a set of points drawn as
+//! small markers facing the camera, e.g. for 3D scatter plots or scanned
+//! point sets.
+//!
+//! # Example
+//!
+//! ```
+//! use larnt::{PointCloud, PointMarker, Vector, render};
+//!
+//! // Points on a helix
+//! let points = (0..100)
+//!     .map(|i| {
+//!         let t = i as f64 * 0.2;
+//!         Vector::new(t.cos(), t.sin(), t * 0.1)
+//!     })
+//!     .collect();
+//! let cloud = PointCloud::builder(points).size(0.05).marker(PointMarker::Dot).build();
+//!
+//! let paths = render(vec![cloud]).eye(Vector::new(4.0, 3.0, 2.0)).call();
+//! assert_eq!(paths.len(), 100);
+//! ```
+
+use crate::bounding_box::BBox;
+use crate::hit::Hit;
+use crate::path::Paths;
+use crate::ray::Ray;
+use crate::scene::camera_basis;
+use crate::shape::{RenderArgs, Shape};
+use crate::vector::Vector;
+use bon::Builder;
+use std::f64::consts::PI;
+
+/// The marker drawn at each point of a [`PointCloud`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PointMarker {
+    /// Two crossing strokes, upright and horizontal seen from the camera.
+    #[default]
+    Cross,
+    /// A small circle, which looks like a dot at small sizes.
+    Dot,
+}
+
+/// A set of points drawn as markers.
+///
+/// Every marker lies in the plane facing the eye, so it keeps its shape from
+/// any viewpoint. The points are not solid: markers are hidden by the shapes
+/// in front of them, but do not hide anything themselves.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Hit, PointCloud, Ray, Shape, Vector};
+///
+/// let cloud = PointCloud::builder(vec![Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 2.0, 3.0)])
+///     .size(0.2)
+///     .build();
+/// let bx = cloud.bounding_box();
+/// assert!(bx.min.all_close(Vector::new(-0.1, -0.1, -0.1)));
+/// assert!(bx.max.all_close(Vector::new(1.1, 2.1, 3.1)));
+///
+/// let ray = Ray::new(Vector::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, -1.0));
+/// assert!(!cloud.intersect(ray).is_ok());
+/// ```
+#[derive(Debug, Clone, Builder)]
+pub struct PointCloud {
+    /// The points to mark.
+    #[builder(start_fn)]
+    pub points: Vec<Vector>,
+    /// The width of a marker in scene units.
+    #[builder(default = 0.02)]
+    pub size: f64,
+    /// The marker drawn at each point.
+    #[builder(default)]
+    pub marker: PointMarker,
+}
+
+impl Shape for PointCloud {
+    fn bounding_box(&self) -> BBox {
+        let r = Vector::new(1.0, 1.0, 1.0).mul_scalar(self.size / 2.0);
+        let bx = BBox::for_vectors(&self.points);
+        BBox::new(bx.min.sub(r), bx.max.add(r))
+    }
+
+    fn contains(&self, _v: Vector, _f: f64) -> bool {
+        false
+    }
+
+    fn intersect(&self, _r: Ray) -> Hit {
+        Hit::no_hit()
+    }
+
+    fn paths(&self, args: &RenderArgs) -> Paths<Vector> {
+        let r = self.size / 2.0;
+        let mut paths = Paths::new();
+        for &p in &self.points {
+            let (_, right, up) = camera_basis(args.eye, p, args.up);
+            let (right, up) = (right.mul_scalar(r), up.mul_scalar(r));
+            match self.marker {
+                PointMarker::Cross => {
+                    paths.new_path().extend([p.sub(right), p.add(right)]);
+                    paths.new_path().extend([p.sub(up), p.add(up)]);
+                }
+                PointMarker::Dot => {
+                    const SEGMENTS: usize = 8;
+                    paths.new_path().extend((0..=SEGMENTS).map(|i| {
+                        let a = 2.0 * PI * i as f64 / SEGMENTS as f64;
+                        p.add(right.mul_scalar(a.cos())).add(up.mul_scalar(a.sin()))
+                    }));
+                }
+            }
+        }
+        paths
+    }
+}
@@ -0,0 +1,232 @@
+//! General quadric surface.
+//!
+//! This module provides the [`Quadric`] shape, the implicit surface
+//!
+//! `Ax² + By² + Cz² + Dxy + Exz + Fyz + Gx + Hy + Iz + J = 0`
+//!
+//! clipped to a bounding box. A single type covers spheres, ellipsoids,
+//! cylinders, cones, paraboloids and hyperboloids, including surfaces that
+//! fold over and cannot be written as `z = f(x, y)`.
+//!
+//! # Example
+//!
+//! ```
+//! use larnt::{BBox, Quadric, QuadricTexture, Vector, render};
+//!
+//! // Hyperboloid of one sheet: x² + y² - z² - 0.25 = 0
+//! let bx = BBox::new(Vector::new(-2.0, -2.0, -1.0), Vector::new(2.0, 2.0, 1.0));
+//! let hyperboloid = Quadric::builder([1.0, 1.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.25], bx)
+//!     .texture(QuadricTexture::Contours(8))
+//!     .build();
+//!
+//! let paths = render(vec![hyperboloid]).eye(Vector::new(4.0, 3.0, 2.0)).call();
+//! ```
+
+use crate::bounding_box::BBox;
+use crate::common::EPS;
+use crate::hit::Hit;
+use crate::path::Paths;
+use crate::ray::Ray;
+use crate::shape::{RenderArgs, Shape};
+use crate::util::marching_squares;
+use crate::vector::Vector;
+use bon::Builder;
+
+/// Texture style for Quadric shapes
+#[derive(Debug, Clone, Copy, Default)]
+pub enum QuadricTexture {
+    /// The intersection curves of the surface with the bounding box faces.
+    #[default]
+    Boundary,
+    /// The boundary curves plus `n` evenly spaced horizontal slices.
+    Contours(usize),
+}
+
+/// A quadric surface clipped to a bounding box.
+///
+/// The coefficients `[A, B, C, D, E, F, G, H, I, J]` define
+/// `Ax² + By² + Cz² + Dxy + Exz + Fyz + Gx + Hy + Iz + J = 0`, points where
+/// the expression is negative are inside.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{BBox, Quadric, Ray, Shape, Vector};
+///
+/// // Paraboloid z = x² + y²
+/// let bx = BBox::new(Vector::new(-1.0, -1.0, 0.0), Vector::new(1.0, 1.0, 1.0));
+/// let paraboloid = Quadric::builder([1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0, 0.0], bx).build();
+///
+/// assert!(paraboloid.contains(Vector::new(0.0, 0.0, 0.5), 0.0));
+/// assert!(!paraboloid.contains(Vector::new(0.9, 0.0, 0.5), 0.0));
+///
+/// let hit = paraboloid.intersect(Ray::new(Vector::new(0.5, 0.0, 2.0), Vector::new(0.0, 0.0, -1.0)));
+/// assert!((hit.t - 1.75).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Builder)]
+pub struct Quadric {
+    /// The coefficients `[A, B, C, D, E, F, G, H, I, J]`.
+    #[builder(start_fn)]
+    pub coefficients: [f64; 10],
+    /// The box the surface is clipped to.
+    #[builder(start_fn)]
+    pub bx: BBox,
+    /// The texture style for the surface.
+    #[builder(default)]
+    pub texture: QuadricTexture,
+    /// The number of grid cells per side used to trace curves.
+    #[builder(default = 64)]
+    pub resolution: usize,
+}
+
+impl Quadric {
+    /// Evaluates the implicit function at `p`.
+    pub fn evaluate(&self, p: Vector) -> f64 {
+        let [a, b, c, d, e, f, g, h, i, j] = self.coefficients;
+        let (x, y, z) = (p.x, p.y, p.z);
+        a * x * x
+            + b * y * y
+            + c * z * z
+            + d * x * y
+            + e * x * z
+            + f * y * z
+            + g * x
+            + h * y
+            + i * z
+            + j
+    }
+
+    /// Returns the gradient of the implicit function at `p`.
+    pub fn gradient(&self, p: Vector) -> Vector {
+        let [a, b, c, d, e, f, g, h, i, _] = self.coefficients;
+        let (x, y, z) = (p.x, p.y, p.z);
+        Vector::new(
+            2.0 * a * x + d * y + e * z + g,
+            2.0 * b * y + d * x + f * z + h,
+            2.0 * c * z + e * x + f * y + i,
+        )
+    }
+
+    /// Evaluates the quadratic part of the implicit function at `v`.
+    fn quadratic_part(&self, v: Vector) -> f64 {
+        let [a, b, c, d, e, f, ..] = self.coefficients;
+        let (x, y, z) = (v.x, v.y, v.z);
+        a * x * x + b * y * y + c * z * z + d * x * y + e * x * z + f * y * z
+    }
+
+    /// Traces the zero set of the implicit function on the parallelogram
+    /// `origin + s * du + t * dv` for `s, t` in `[0, 1]` using marching squares.
+    fn trace(&self, origin: Vector, du: Vector, dv: Vector, paths: &mut Paths<Vector>) {
+        let n = self.resolution.max(1);
+        let position = |i: usize, j: usize| {
+            origin
+                .add(du.mul_scalar(i as f64 / n as f64))
+                .add(dv.mul_scalar(j as f64 / n as f64))
+        };
+        let values: Vec<f64> = (0..=n)
+            .flat_map(|i| (0..=n).map(move |j| (i, j)))
+            .map(|(i, j)| self.evaluate(position(i, j)))
+            .collect();
+        let value = |i: usize, j: usize| values[i * (n + 1) + j];
+
+        let segments = marching_squares(n, value);
+
+        // Linear interpolation leaves points slightly off the surface, which would make
+        // them fail visibility tests, so they are refined with Newton steps in the plane
+        let normal = du.cross(dv).normalize();
+        paths.extend(segments.map(|(i, j, dir)| {
+            let (i2, j2) = if dir == 0 { (i + 1, j) } else { (i, j + 1) };
+            let (v1, v2) = (value(i, j), value(i2, j2));
+            let t = v1 / (v1 - v2);
+            let mut p = position(i, j).add(position(i2, j2).sub(position(i, j)).mul_scalar(t));
+            for _ in 0..4 {
+                let g = self.gradient(p);
+                let g = g.sub(normal.mul_scalar(g.dot(normal)));
+                let g_sq = g.length_squared();
+                if g_sq < EPS {
+                    break;
+                }
+                p = p.sub(g.mul_scalar(self.evaluate(p) / g_sq));
+            }
+            p
+        }));
+    }
+
+    /// Boundary texture: intersection curves with the six bounding box faces.
+    fn paths_boundary(&self, paths: &mut Paths<Vector>) {
+        let (min, size) = (self.bx.min, self.bx.size());
+        let dx = Vector::new(size.x, 0.0, 0.0);
+        let dy = Vector::new(0.0, size.y, 0.0);
+        let dz = Vector::new(0.0, 0.0, size.z);
+        for (offset, du, dv) in [(dx, dy, dz), (dy, dx, dz), (dz, dx, dy)] {
+            self.trace(min, du, dv, paths);
+            self.trace(min.add(offset), du, dv, paths);
+        }
+    }
+
+    /// Contours texture: `n` evenly spaced horizontal slices.
+    fn paths_contours(&self, n: usize, paths: &mut Paths<Vector>) {
+        let (min, size) = (self.bx.min, self.bx.size());
+        let dx = Vector::new(size.x, 0.0, 0.0);
+        let dy = Vector::new(0.0, size.y, 0.0);
+        for k in 1..=n {
+            let z = size.z * k as f64 / (n + 1) as f64;
+            self.trace(min.add(Vector::new(0.0, 0.0, z)), dx, dy, paths);
+        }
+    }
+}
+
+impl Shape for Quadric {
+    fn bounding_box(&self) -> BBox {
+        self.bx
+    }
+
+    fn contains(&self, v: Vector, f: f64) -> bool {
+        if !BBox::new(self.bx.min.sub_scalar(f), self.bx.max.add_scalar(f)).contains(v) {
+            return false;
+        }
+        self.evaluate(v) - f * self.gradient(v).length() <= 0.0
+    }
+
+    fn intersect(&self, r: Ray) -> Hit {
+        let a = self.quadratic_part(r.direction);
+        let b = self.gradient(r.origin).dot(r.direction);
+        let c = self.evaluate(r.origin);
+
+        let roots = if a.abs() < EPS {
+            if b.abs() < EPS {
+                return Hit::no_hit();
+            }
+            [-c / b, f64::NAN]
+        } else {
+            let q = b * b - 4.0 * a * c;
+            if q < 0.0 {
+                return Hit::no_hit();
+            }
+            let s = q.sqrt();
+            let (t0, t1) = ((-b - s) / (2.0 * a), (-b + s) / (2.0 * a));
+            [t0.min(t1), t0.max(t1)]
+        };
+
+        let bx = BBox::new(self.bx.min.sub_scalar(EPS), self.bx.max.add_scalar(EPS));
+        roots
+            .into_iter()
+            .find(|&t| t > 1e-2 && bx.contains(r.position(t)))
+            .map_or_else(Hit::no_hit, |t| {
+                Hit::new(t).with_normal(self.gradient(r.position(t)))
+            })
+    }
+
+    fn paths(&self, _args: &RenderArgs) -> Paths<Vector> {
+        let mut paths = Paths::new();
+        self.paths_boundary(&mut paths);
+        if let QuadricTexture::Contours(n) = self.texture {
+            self.paths_contours(n, &mut paths);
+        }
+        paths
+    }
+
+    fn view_dependent(&self) -> bool {
+        false
+    }
+}
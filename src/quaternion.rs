@@ -0,0 +1,202 @@
+//! Rotation quaternions.
+//!
+//! This module provides the [`Quaternion`] struct for representing and
+//! smoothly interpolating 3D rotations, for example to fly a camera between
+//! two orientations around arbitrary axes.
+//!
+//! # Example
+//!
+//! ```
+//! use larnt::{Matrix, Quaternion, Vector, radians};
+//!
+//! let a = Quaternion::from_axis_angle(Vector::new(0.0, 0.0, 1.0), radians(0.0));
+//! let b = Quaternion::from_axis_angle(Vector::new(1.0, 1.0, 0.0), radians(120.0));
+//!
+//! // Halfway between the two orientations
+//! let half = a.slerp(&b, 0.5);
+//! let expected = Matrix::rotate(Vector::new(1.0, 1.0, 0.0), radians(60.0));
+//! let v = Vector::new(0.0, 0.0, 1.0);
+//! assert!(half.rotate(v).all_close(expected.mul_vector(v)));
+//! ```
+
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+/// A unit quaternion representing a rotation.
+///
+/// Rotations follow the same convention as [`Matrix::rotate`], so
+/// `Quaternion::from_axis_angle(v, a).to_matrix()` equals
+/// `Matrix::rotate(v, a)`.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Matrix, Quaternion, Vector, radians};
+///
+/// let axis = Vector::new(1.0, 2.0, 3.0);
+/// let q = Quaternion::from_axis_angle(axis, radians(40.0));
+/// let m = Matrix::rotate(axis, radians(40.0));
+///
+/// let p = Vector::new(0.5, -1.0, 2.0);
+/// assert!(q.rotate(p).all_close(m.mul_vector(p)));
+/// assert!(q.to_matrix().mul_vector(p).all_close(m.mul_vector(p)));
+/// assert!(Quaternion::from_matrix(&m).rotate(p).all_close(m.mul_vector(p)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Quaternion {
+    /// Creates a quaternion from its components.
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    /// Returns the identity rotation.
+    pub fn identity() -> Self {
+        Quaternion::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Creates the rotation around the axis `v` by angle `a` (in radians).
+    pub fn from_axis_angle(v: Vector, a: f64) -> Self {
+        let v = v.normalize().mul_scalar(-(a / 2.0).sin());
+        Quaternion::new((a / 2.0).cos(), v.x, v.y, v.z)
+    }
+
+    /// Extracts the rotation from the upper 3x3 part of `m`.
+    ///
+    /// The part must be a pure rotation, without scale or shear.
+    pub fn from_matrix(m: &Matrix) -> Self {
+        let trace = m.x00 + m.x11 + m.x22;
+        let q = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quaternion::new(
+                s / 4.0,
+                (m.x21 - m.x12) / s,
+                (m.x02 - m.x20) / s,
+                (m.x10 - m.x01) / s,
+            )
+        } else if m.x00 > m.x11 && m.x00 > m.x22 {
+            let s = (1.0 + m.x00 - m.x11 - m.x22).sqrt() * 2.0;
+            Quaternion::new(
+                (m.x21 - m.x12) / s,
+                s / 4.0,
+                (m.x01 + m.x10) / s,
+                (m.x02 + m.x20) / s,
+            )
+        } else if m.x11 > m.x22 {
+            let s = (1.0 + m.x11 - m.x00 - m.x22).sqrt() * 2.0;
+            Quaternion::new(
+                (m.x02 - m.x20) / s,
+                (m.x01 + m.x10) / s,
+                s / 4.0,
+                (m.x12 + m.x21) / s,
+            )
+        } else {
+            let s = (1.0 + m.x22 - m.x00 - m.x11).sqrt() * 2.0;
+            Quaternion::new(
+                (m.x10 - m.x01) / s,
+                (m.x02 + m.x20) / s,
+                (m.x12 + m.x21) / s,
+                s / 4.0,
+            )
+        };
+        q.normalize()
+    }
+
+    /// Returns the rotation matrix of this quaternion.
+    pub fn to_matrix(&self) -> Matrix {
+        let Quaternion { w, x, y, z } = *self;
+        Matrix {
+            x00: 1.0 - 2.0 * (y * y + z * z),
+            x01: 2.0 * (x * y - w * z),
+            x02: 2.0 * (x * z + w * y),
+            x03: 0.0,
+            x10: 2.0 * (x * y + w * z),
+            x11: 1.0 - 2.0 * (x * x + z * z),
+            x12: 2.0 * (y * z - w * x),
+            x13: 0.0,
+            x20: 2.0 * (x * z - w * y),
+            x21: 2.0 * (y * z + w * x),
+            x22: 1.0 - 2.0 * (x * x + y * y),
+            x23: 0.0,
+            x30: 0.0,
+            x31: 0.0,
+            x32: 0.0,
+            x33: 1.0,
+        }
+    }
+
+    /// Rotates the vector `v`.
+    pub fn rotate(&self, v: Vector) -> Vector {
+        let q = Vector::new(self.x, self.y, self.z);
+        let t = q.cross(v).mul_scalar(2.0);
+        v.add(t.mul_scalar(self.w)).add(q.cross(t))
+    }
+
+    /// Returns the rotation `self` applied after `other`.
+    pub fn mul(&self, other: &Quaternion) -> Quaternion {
+        let (a, b) = (self, other);
+        Quaternion::new(
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        )
+    }
+
+    /// Returns the inverse rotation.
+    pub fn conjugate(&self) -> Quaternion {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Computes the dot product of two quaternions.
+    pub fn dot(&self, other: &Quaternion) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the quaternion scaled to unit length.
+    pub fn normalize(&self) -> Quaternion {
+        let d = self.dot(self).sqrt();
+        Quaternion::new(self.w / d, self.x / d, self.y / d, self.z / d)
+    }
+
+    /// Spherically interpolates from `self` (`t = 0`) to `other` (`t = 1`).
+    ///
+    /// The rotation speed is constant and the interpolation takes the
+    /// shortest way around.
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Quaternion {
+        let mut dot = self.dot(other);
+        let mut other = *other;
+        if dot < 0.0 {
+            dot = -dot;
+            other = Quaternion::new(-other.w, -other.x, -other.y, -other.z);
+        }
+
+        // Nearly parallel rotations fall back to linear interpolation
+        let (a, b) = if dot > 0.9995 {
+            (1.0 - t, t)
+        } else {
+            let theta = dot.acos();
+            let sin = theta.sin();
+            (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+        Quaternion::new(
+            a * self.w + b * other.w,
+            a * self.x + b * other.x,
+            a * self.y + b * other.y,
+            a * self.z + b * other.z,
+        )
+        .normalize()
+    }
+}
@@ -15,14 +15,19 @@
 //! paths.write_to_png("output.png", 1024.0, 1024.0).expect("Failed to write PNG");
 //! ```
 
-use crate::filter::ClipFilter;
+use crate::bounding_box::BBox;
+use crate::common::EPS;
+use crate::filter::{ClipFilter, Filter};
 use crate::matrix::Matrix;
-use crate::path::Paths;
+use crate::path::{Paths, path_filter, recursive_subdivide};
 use crate::ray::Ray;
 use crate::shape::{RenderArgs, Shape};
 use crate::tree::Tree;
+use crate::util::radians;
 use crate::vector::Vector;
-use bon::builder;
+use bon::{Builder, builder};
+use std::f64::consts::{FRAC_PI_2, PI};
+use std::sync::{Arc, OnceLock};
 
 /// Renders a collection of shapes to 2D paths.
 ///
@@ -33,18 +38,43 @@ use bon::builder;
 /// 4. Simplifies paths (if `step > 0.0`)
 /// 5. Projects to 2D screen space
 ///
+/// `render` runs on the calling thread. With the `rayon` feature,
+/// `par_render` runs path generation and visibility testing in parallel
+/// for shapes that are `Send + Sync`, as does [`Scene::render`]. The output
+/// is identical.
+///
+/// The returned points are in screen coordinates (`0..width`, `0..height`),
+/// their z coordinate is the normalized device depth (`-1` at `near`, `1` at
+/// `far`), which [`Paths::to_image`] can use for depth cueing. With
+/// `linear_depth` the z coordinate is instead the camera-space depth, the
+/// distance from the eye along the viewing direction, for custom fog or
+/// z-sorting.
+///
+/// A canvas without area (a `width` or `height` that is zero, negative or
+/// NaN) renders no paths, as does an empty list of shapes.
+///
 /// # Arguments
 ///
 /// * `shapes` - The shapes to render (passed as the start argument to the builder)
 /// * `eye` - Camera position
 /// * `center` - Point the camera looks at (default: origin)
 /// * `up` - Up direction vector (default: `+Z`)
+/// * `roll` - Camera roll in degrees, see below (default: 0)
 /// * `width` - Output width in pixels (default: 1024)
 /// * `height` - Output height in pixels (default: 1024)
 /// * `fovy` - Vertical field of view in degrees (default: 50)
-/// * `near` - Near clipping plane distance (default: 0.1)
+/// * `near` - Near clipping plane distance, paths are cut where they cross it (default: 0.1)
 /// * `far` - Far clipping plane distance (default: 1000)
-/// * `step` - Path subdivision step size for visibility testing (default: 1.0)
+/// * `step` - Path subdivision step size for visibility testing, in pixels (default: 1.0)
+/// * `step_auto` - Derive `step` from the scene and the output size, between
+///   1/64 and 1/2 of a pixel, see below; an explicit `step` takes precedence
+/// * `min_arc_angle` - Angle in degrees at which curved outlines stop subdividing, see below (default: 2)
+/// * `linear_depth` - Output camera-space depth as the z coordinate (default: false)
+/// * `projection` - How the view is mapped to the canvas (default: [`Projection::Perspective`])
+/// * `join` - Join paths whose endpoints are within this many pixels, see below (default: off)
+/// * `simplify` - Simplify paths with this threshold in pixels, see below (default: off)
+/// * `shading` - Thin out lines by the light, see below (default: [`Shading::None`])
+/// * `light` - Direction toward the light for `shading` (default: toward the eye)
 ///
 /// # Example
 ///
@@ -55,50 +85,1282 @@ use bon::builder;
 ///
 /// let paths = render(vec![cube]).eye(Vector::new(4.0, 3.0, 2.0)).call();
 /// ```
+///
+/// With `linear_depth`, every point of the cube is within its half diagonal of
+/// the distance between the eye and the cube center:
+///
+/// ```
+/// use larnt::{Cube, Vector, render};
+///
+/// let cube = Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build();
+/// let eye = Vector::new(4.0, 3.0, 2.0);
+///
+/// let paths = render(vec![cube]).eye(eye).linear_depth(true).call();
+/// let (d, r) = (eye.length(), 3.0_f64.sqrt());
+/// assert!(paths.iter_paths().flatten().all(|v| v.z > d - r - 1e-6 && v.z < d + r + 1e-6));
+/// ```
+///
+/// # Automatic step
+///
+/// Paths are chopped until their pieces are at most `step` pixels long on
+/// screen, and curved shapes use it as their tolerance for adaptive arcs. With
+/// `step_auto`, the step is half a pixel, made finer for scenes that cover
+/// only a small part of the canvas so that the projected bounding box of the
+/// scene spans at least 512 steps. The step stays between 1/64 and 1/2 of a
+/// pixel, so scenes that project to a point do not chop paths into millions
+/// of pieces. It falls back to half a pixel when the bounding box is
+/// unbounded or reaches behind the eye.
+///
+/// ```
+/// use larnt::{Sphere, Vector, render};
+///
+/// // A small sphere far away covers only about a pixel, so the default step
+/// // leaves only a handful of points on its outline
+/// let sphere = Sphere::builder(Vector::new(0.0, 0.0, 0.0), 0.01).build();
+/// let eye = Vector::new(0.0, -20.0, 0.0);
+/// let coarse = render(vec![sphere.clone()]).eye(eye).call();
+/// let fine = render(vec![sphere]).eye(eye).step_auto().call();
+/// assert!(coarse.iter_paths().flatten().count() < 10);
+/// assert!(fine.iter_paths().flatten().count() > 100);
+/// ```
+///
+/// # Curve resolution
+///
+/// The outlines of spheres, cylinders, cones and other round shapes are arcs
+/// subdivided until their pieces are shorter than `step` on screen, or span
+/// less than `min_arc_angle` degrees. Raising it caps the number of points on
+/// a full circle at `720 / min_arc_angle`, trading smoothness for speed and
+/// file size.
+///
+/// ```
+/// use larnt::{Sphere, Vector, render};
+///
+/// let sphere = Sphere::builder(Vector::new(0.0, 0.0, 0.0), 1.0).build();
+/// let eye = Vector::new(0.0, -4.0, 0.0);
+/// let smooth = render(vec![sphere.clone()]).eye(eye).call();
+/// let coarse = render(vec![sphere]).eye(eye).min_arc_angle(30.0).call();
+///
+/// let points = |paths: &larnt::Paths<Vector>| paths.iter_paths().flatten().count();
+/// assert!(points(&smooth) > 100);
+/// assert!(points(&coarse) <= 720 / 30 + 1);
+/// ```
+///
+/// # Cleanup
+///
+/// `join` stitches the rendered paths with [`Paths::join`] and `simplify`
+/// then drops nearly collinear points with [`Paths::simplify`], both on the
+/// screen space paths, so the tolerances are in pixels. Joining first lets
+/// simplification also remove the points where the pieces met.
+///
+/// ```
+/// use larnt::{Cube, Vector, render};
+///
+/// let cube = || Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build();
+/// let eye = Vector::new(4.0, 3.0, 2.0);
+/// let raw = render(vec![cube()]).eye(eye).call();
+/// let clean = render(vec![cube()]).eye(eye).join(1e-6).simplify(1e-3).call();
+///
+/// assert_eq!(clean.len(), raw.join(1e-6).simplify(1e-3).len());
+/// assert!(clean.len() < raw.len());
+/// ```
+///
+/// # Shading
+///
+/// With [`Shading::Hatch`], the texture lines of a surface are thinned out by
+/// `max(0, n · l)`, the cosine between the surface normal `n` and the
+/// direction `l` toward the `light`. A surface facing the light keeps only a
+/// few of its lines, one facing away keeps them all, which turns dense
+/// textures into hatching with pencil-like tones. The normals come from
+/// [`Hit::normal`](crate::Hit::normal), surfaces without normals are not
+/// thinned.
+///
+/// ```
+/// use larnt::{Shading, Sphere, SphereTexture, Vector, render};
+///
+/// let sphere = Sphere::builder(Vector::new(0.0, 0.0, 0.0), 1.0)
+///     .texture(SphereTexture::random_equators(7).n(100).call())
+///     .build();
+/// let paths = render(vec![sphere])
+///     .eye(Vector::new(0.0, -5.0, 0.0))
+///     .width(256.0)
+///     .height(256.0)
+///     .shading(Shading::Hatch)
+///     .light(Vector::new(1.0, 0.0, 0.0))
+///     .call();
+///
+/// // The right half faces the light and is drawn with fewer lines
+/// let length = |right: bool| -> f64 {
+///     let segments = paths.iter_paths().flat_map(|path| path.windows(2));
+///     segments.filter(|w| (w[0].x > 128.0) == right).map(|w| w[0].distance(w[1])).sum()
+/// };
+/// assert!(length(true) < 0.7 * length(false));
+/// ```
+///
+/// # Roll
+///
+/// `roll` rotates the up vector about the view direction after `up` has
+/// oriented the camera, so `up` still decides which way is up before the
+/// roll. A positive roll tilts the camera clockwise, turning the image
+/// counterclockwise.
+///
+/// ```
+/// use larnt::{Primitive, Segments, Vector, render};
+///
+/// // A segment pointing to the right of the camera
+/// let segment: Primitive = Segments::new(vec![(Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0))]).into();
+/// let paths = render(vec![segment])
+///     .eye(Vector::new(0.0, -5.0, 0.0))
+///     .roll(90.0)
+///     .width(100.0)
+///     .height(100.0)
+///     .call();
+///
+/// // After a quarter roll it points up
+/// let bx = paths.bounding_box();
+/// assert!(bx.size().x < 1e-6);
+/// assert!(bx.min.y > 49.0 && bx.max.y > 60.0);
+/// ```
+///
+/// # Near plane
+///
+/// Paths are cut where they cross the near plane, so geometry reaching
+/// behind the eye keeps its visible part and never wraps around the canvas.
+///
+/// ```
+/// use larnt::{Primitive, Segments, Vector, render};
+///
+/// // A segment passing under the eye and ending behind it
+/// let segment: Primitive = Segments::new(vec![(Vector::new(3.0, -2.0, -0.5), Vector::new(-3.0, 5.0, -0.5))]).into();
+/// let paths = render(vec![segment])
+///     .eye(Vector::new(0.0, 0.0, 0.0))
+///     .center(Vector::new(1.0, 0.0, 0.0))
+///     .width(100.0)
+///     .height(100.0)
+///     .call();
+///
+/// assert_eq!(paths.len(), 1);
+/// for v in paths.iter_paths().flatten() {
+///     assert!((0.0..=100.0).contains(&v.x) && (0.0..=100.0).contains(&v.y));
+/// }
+/// // It leaves through the right and the bottom edge of the canvas
+/// let bx = paths.bounding_box();
+/// assert!(bx.max.x > 99.0 && bx.min.y < 1.0);
+/// ```
+///
+/// # Degenerate input
+///
+/// ```
+/// use larnt::{Cube, Primitive, Projection, Vector, render};
+///
+/// let eye = Vector::new(4.0, 3.0, 2.0);
+/// assert!(render(Vec::<Primitive>::new()).eye(eye).call().is_empty());
+///
+/// let cube = Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build();
+/// for projection in [Projection::Perspective, Projection::Equirectangular] {
+///     let paths = render(vec![cube.clone()]).eye(eye).width(0.0).height(0.0).projection(projection).call();
+///     assert!(paths.is_empty());
+/// }
+/// ```
 #[builder]
 pub fn render<T: Shape>(
     #[builder(start_fn)] shapes: Vec<T>,
     eye: Vector,
     #[builder(default = Vector::new(0.0, 0.0, 0.0))] center: Vector,
     #[builder(default = Vector::new(0.0, 0.0, 1.0))] up: Vector,
+    #[builder(default)] roll: f64,
     #[builder(default = 1024.0)] width: f64,
     #[builder(default = 1024.0)] height: f64,
     #[builder(default = 50.0)] fovy: f64,
     #[builder(default = 0.1)] near: f64,
     #[builder(default = 1e3)] far: f64,
-    #[builder(default = 1.0)] step: f64,
+    step: Option<f64>,
+    #[builder(default, with = || true)] step_auto: bool,
+    #[builder(default = 2.0)] min_arc_angle: f64,
+    #[builder(default)] linear_depth: bool,
+    #[builder(default)] projection: Projection,
+    join: Option<f64>,
+    simplify: Option<f64>,
+    #[builder(default)] shading: Shading,
+    light: Option<Vector>,
 ) -> Paths<Vector> {
-    let aspect = width / height;
-    let matrix = Matrix::look_at(eye, center, up);
-    let matrix = matrix.with_perspective(fovy, aspect, near, far);
-
-    let viewport_mat = Matrix::translate(Vector::new(1.0, 1.0, 0.0)).scaled(Vector::new(
-        width / 2.0,
-        height / 2.0,
-        1.0,
-    ));
-
-    let args = RenderArgs {
-        screen_mat: viewport_mat.mul(&matrix),
+    let camera = Camera {
         eye,
+        center,
         up,
+        roll,
         width,
         height,
+        fovy,
+        near,
+        far,
         step,
+        step_auto,
+        min_arc_angle,
+        linear_depth,
+        projection,
+        join,
+        simplify,
+        shading,
+        light,
     };
+    render_camera(shapes, &camera, Serial)
+}
+
+/// Renders a collection of shapes to 2D paths in parallel.
+///
+/// Renders the same paths as [`render`] with the options of `camera`, but
+/// generates the paths of the shapes and tests them for visibility on the
+/// rayon thread pool, which needs the shapes to be `Send + Sync`.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Camera, Cube, Vector, par_render, render};
+///
+/// let cubes: Vec<_> = (0..10)
+///     .map(|i| {
+///         let p = Vector::new(i as f64 * 2.0, 0.0, 0.0);
+///         Cube::builder(p.sub_scalar(0.5), p.add_scalar(0.5)).build()
+///     })
+///     .collect();
+/// let eye = Vector::new(9.0, -20.0, 5.0);
+///
+/// let paths = par_render(cubes.clone(), &Camera::builder(eye).center(Vector::new(9.0, 0.0, 0.0)).build());
+/// let serial = render(cubes).eye(eye).center(Vector::new(9.0, 0.0, 0.0)).call();
+/// assert!(paths.iter_paths().eq(serial.iter_paths()));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_render<T: Shape + Send + Sync>(shapes: Vec<T>, camera: &Camera) -> Paths<Vector> {
+    render_camera(shapes, camera, Parallel)
+}
 
+/// Renders `shapes` as seen by `camera`, the body of [`render`] and
+/// `par_render`.
+fn render_camera<T: Shape, E: Exec<[T]> + Exec<Tree<T>>>(
+    shapes: Vec<T>,
+    camera: &Camera,
+    exec: E,
+) -> Paths<Vector> {
+    if !camera.has_area() {
+        return Paths::new();
+    }
+    let view = View::new(camera, || {
+        shapes
+            .iter()
+            .map(|shape| shape.bounding_box())
+            .reduce(|a, b| a.extend(b))
+            .unwrap_or_default()
+    });
+    let args = view.args();
+
+    let indices: Vec<_> = (0..shapes.len()).collect();
     let mut paths = Paths::new();
-    for shape in shapes.iter() {
-        paths.extend(shape.paths(&args));
+    for shape_paths in exec.map(&shapes[..], &indices, |shapes, &i| shapes[i].paths(&args)) {
+        paths.extend(shape_paths);
+    }
+
+    view.project(paths, &Tree::new(shapes), &mut Progress::new(None), exec)
+}
+
+/// A collection of shapes that keeps camera-independent work between renders.
+///
+/// The first call to [`render`](Scene::render) builds the BVH tree and stores
+/// the paths of every shape whose [`view_dependent`](Shape::view_dependent)
+/// returns `false`. Later renders, typically from another eye position, reuse
+/// both and only regenerate the view-dependent paths before the visibility and
+/// projection pass. Adding or removing shapes discards the cache.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Cube, Scene, Sphere, Vector};
+///
+/// let mut scene = Scene::new();
+/// scene.add(Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build());
+/// scene.add(Sphere::builder(Vector::new(0.0, 0.0, 2.0), 1.0).build());
+///
+/// let frames: Vec<_> = (0..4)
+///     .map(|i| {
+///         let angle = i as f64 * std::f64::consts::FRAC_PI_2;
+///         let eye = Vector::new(4.0 * angle.cos(), 4.0 * angle.sin(), 2.0);
+///         scene.render().eye(eye).call()
+///     })
+///     .collect();
+/// assert!(frames.iter().all(|paths| paths.len() > 0));
+///
+/// // Nothing to draw, or nowhere to draw it
+/// assert!(Scene::new().render().eye(Vector::new(4.0, 3.0, 2.0)).call().is_empty());
+/// assert!(scene.render().eye(Vector::new(4.0, 3.0, 2.0)).width(0.0).call().is_empty());
+/// ```
+#[derive(Clone, Default)]
+pub struct Scene {
+    shapes: Vec<Arc<dyn Shape + Send + Sync>>,
+    /// The role of each shape, parallel to `shapes`.
+    roles: Vec<Role>,
+    compiled: OnceLock<Compiled>,
+}
+
+/// How a shape of a [`Scene`] takes part in rendering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    /// Drawn and hiding what is behind it.
+    Solid,
+    /// Drawn, but never hiding anything.
+    NonOccluding,
+    /// Hiding what is behind it, but not drawn.
+    Occluder,
+}
+
+impl Role {
+    fn drawn(self) -> bool {
+        self != Role::Occluder
+    }
+
+    fn occludes(self) -> bool {
+        self != Role::NonOccluding
+    }
+}
+
+/// The camera-independent state of a [`Scene`].
+#[derive(Clone)]
+struct Compiled {
+    tree: Tree<Arc<dyn Shape + Send + Sync>>,
+    /// The cached paths of each shape, `None` for view-dependent shapes.
+    paths: Vec<Option<Paths<Vector>>>,
+}
+
+impl Scene {
+    /// Creates an empty scene.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a shape to the scene.
+    pub fn add<S: Shape + Send + Sync + 'static>(&mut self, shape: S) {
+        self.add_arc(Arc::new(shape));
+    }
+
+    /// Adds a shared shape to the scene.
+    pub fn add_arc(&mut self, shape: Arc<dyn Shape + Send + Sync>) {
+        self.push(shape, Role::Solid);
+    }
+
+    /// Adds a shape that is drawn but does not hide anything, e.g. guides and
+    /// annotations.
+    ///
+    /// Its paths are still hidden by the other shapes. Unlike wrapping it in a
+    /// [`Wireframe`](crate::Wireframe), the shape keeps its `contains`, and
+    /// it is left out of silhouettes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Cube, Scene, Sphere, Vector};
+    ///
+    /// let eye = Vector::new(4.0, 3.0, 2.0);
+    /// let sphere = || Sphere::builder(Vector::new(0.0, 0.0, 0.0), 1.0).build();
+    /// let mut plain = Scene::new();
+    /// plain.add(sphere());
+    ///
+    /// // A box around the sphere, which stays fully visible inside it
+    /// let mut scene = Scene::new();
+    /// scene.add(sphere());
+    /// scene.add_non_occluding(Cube::builder(Vector::new(-1.5, -1.5, -1.5), Vector::new(1.5, 1.5, 1.5)).build());
+    ///
+    /// let paths = scene.render().eye(eye).call();
+    /// let points = |paths: &larnt::Paths<Vector>| paths.iter_paths().flatten().count();
+    /// assert!(points(&paths) > points(&plain.render().eye(eye).call()));
+    /// ```
+    pub fn add_non_occluding<S: Shape + Send + Sync + 'static>(&mut self, shape: S) {
+        self.push(Arc::new(shape), Role::NonOccluding);
+    }
+
+    /// Adds a shape that hides what is behind it but is not drawn itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Cube, Scene, Sphere, Vector};
+    ///
+    /// let eye = Vector::new(0.0, -5.0, 0.0);
+    /// let mut scene = Scene::new();
+    /// scene.add(Sphere::builder(Vector::new(0.0, 0.0, 0.0), 1.0).build());
+    /// let full = scene.render().eye(eye).width(256.0).height(256.0).call();
+    ///
+    /// // An invisible box covering the left half of the sphere
+    /// scene.add_occluder(Cube::builder(Vector::new(-2.0, -3.0, -2.0), Vector::new(0.0, -2.0, 2.0)).build());
+    /// let half = scene.render().eye(eye).width(256.0).height(256.0).call();
+    ///
+    /// assert!(half.bounding_box().min.x > full.bounding_box().min.x + 50.0);
+    /// assert_eq!(half.bounding_box().max.x, full.bounding_box().max.x);
+    /// ```
+    pub fn add_occluder<S: Shape + Send + Sync + 'static>(&mut self, shape: S) {
+        self.push(Arc::new(shape), Role::Occluder);
+    }
+
+    fn push(&mut self, shape: Arc<dyn Shape + Send + Sync>, role: Role) {
+        self.shapes.push(shape);
+        self.roles.push(role);
+        self.compiled = OnceLock::new();
+    }
+
+    /// Removes and returns the shape at `index`, shifting later shapes down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Arc<dyn Shape + Send + Sync> {
+        let shape = self.shapes.remove(index);
+        self.roles.remove(index);
+        self.compiled = OnceLock::new();
+        shape
+    }
+
+    /// Removes all shapes from the scene.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Scene, Sphere, Vector};
+    ///
+    /// let mut scene = Scene::new();
+    /// for frame in 0..3 {
+    ///     scene.clear();
+    ///     scene.add(Sphere::builder(Vector::new(frame as f64, 0.0, 0.0), 1.0).build());
+    ///     scene.add(Sphere::builder(Vector::new(0.0, frame as f64, 0.0), 1.0).build());
+    ///     assert_eq!(scene.len(), 2);
+    /// }
+    ///
+    /// scene.remove(0);
+    /// assert_eq!(scene.len(), 1);
+    /// scene.clear();
+    /// assert!(scene.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.shapes.clear();
+        self.roles.clear();
+        self.compiled = OnceLock::new();
+    }
+
+    /// Returns the number of shapes in the scene.
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    /// Returns `true` if the scene contains no shapes.
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// Returns an iterator over the shapes in the scene, in insertion order.
+    pub fn shapes(&self) -> impl Iterator<Item = &Arc<dyn Shape + Send + Sync>> {
+        self.shapes.iter()
+    }
+
+    /// Returns the union of the bounding boxes of all shapes.
+    ///
+    /// An empty scene has the default, zero-sized box at the origin.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Scene, Shape, Sphere, Vector};
+    ///
+    /// let mut scene = Scene::new();
+    /// assert_eq!(scene.bounding_box().size(), Vector::new(0.0, 0.0, 0.0));
+    ///
+    /// scene.add(Sphere::builder(Vector::new(2.0, 2.0, 2.0), 1.0).build());
+    /// scene.add(Sphere::builder(Vector::new(5.0, 2.0, 2.0), 1.0).build());
+    /// let bx = scene.bounding_box();
+    /// assert_eq!(bx.min, Vector::new(1.0, 1.0, 1.0));
+    /// assert_eq!(bx.max, Vector::new(6.0, 3.0, 3.0));
+    ///
+    /// assert_eq!(scene.shapes().filter(|shape| shape.contains(Vector::new(2.0, 2.0, 2.0), 0.0)).count(), 1);
+    /// ```
+    pub fn bounding_box(&self) -> BBox {
+        self.shapes
+            .iter()
+            .map(|shape| shape.bounding_box())
+            .reduce(|a, b| a.extend(b))
+            .unwrap_or_default()
+    }
+
+    /// Computes an `(eye, center)` pair that frames the whole scene.
+    ///
+    /// `center` is the center of the [`bounding_box`](Scene::bounding_box)
+    /// and the eye looks at it from `direction`, just far enough for every
+    /// corner of the box to fall within the vertical field of view `fovy` (in
+    /// degrees) of a square canvas. Pass the same `up` and `fovy` to the
+    /// render call.
+    ///
+    /// Returns `None` when there is nothing to frame, for an empty scene, a
+    /// scene whose bounding box is a single point or unbounded, or a zero
+    /// `direction`, which would all put the eye at the center or at infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Cube, Scene, Vector};
+    ///
+    /// let mut scene = Scene::new();
+    /// scene.add(Cube::builder(Vector::new(8.0, -1.0, -1.0), Vector::new(10.0, 1.0, 1.0)).build());
+    ///
+    /// let up = Vector::new(0.0, 0.0, 1.0);
+    /// let (eye, center) = scene.fit_camera(Vector::new(1.0, 1.0, 1.0), up, 50.0).unwrap();
+    /// assert_eq!(center, Vector::new(9.0, 0.0, 0.0));
+    ///
+    /// let paths = scene.render().eye(eye).center(center).up(up).fovy(50.0).width(512.0).height(512.0).call();
+    /// let bx = paths.bounding_box();
+    /// assert!(bx.min.x >= -1e-6 && bx.min.y >= -1e-6 && bx.max.x <= 512.0 + 1e-6 && bx.max.y <= 512.0 + 1e-6);
+    /// assert!(bx.min.x < 1.0 || bx.min.y < 1.0 || bx.max.x > 511.0 || bx.max.y > 511.0);
+    ///
+    /// // An empty scene has nothing to frame
+    /// assert_eq!(Scene::new().fit_camera(Vector::new(1.0, 1.0, 1.0), up, 50.0), None);
+    /// ```
+    pub fn fit_camera(&self, direction: Vector, up: Vector, fovy: f64) -> Option<(Vector, Vector)> {
+        let bx = self.bounding_box();
+        let center = bx.center();
+        let direction = direction.normalize();
+        let s = direction.cross(up).normalize().mul_scalar(-1.0);
+        let u = s.cross(direction).mul_scalar(-1.0);
+        let tan = radians(fovy / 2.0).tan();
+
+        let distance = (0..8)
+            .map(|i| {
+                let corner = bx.anchor(Vector::new(
+                    (i & 1) as f64,
+                    (i >> 1 & 1) as f64,
+                    (i >> 2 & 1) as f64,
+                ));
+                let q = corner.sub(center);
+                q.dot(direction) + q.dot(s).abs().max(q.dot(u).abs()) / tan
+            })
+            .fold(0.0, f64::max);
+
+        (distance > 0.0 && distance.is_finite())
+            .then(|| (center.add(direction.mul_scalar(distance)), center))
+    }
+
+    /// Renders the scene to 2D paths as seen by `camera`.
+    ///
+    /// Equivalent to [`Scene::render`] with the options stored in the
+    /// [`Camera`], for rendering several times with the same settings.
+    pub fn render_with(&self, camera: &Camera) -> Paths<Vector> {
+        self.render_view(camera, false, &mut Progress::new(None))
+    }
+}
+
+#[bon::bon]
+impl Scene {
+    /// Renders the scene to 2D paths.
+    ///
+    /// Takes the same options as the [`render`] function, including
+    /// `step_auto`, plus `silhouette` (default: false) and `on_progress`.
+    ///
+    /// # Progress
+    ///
+    /// `on_progress` is called with the fraction of the work done, from `0.0`
+    /// to `1.0`, as shapes generate their paths and as the paths are tested for
+    /// visibility. The fractions are increasing and the last call is always
+    /// with `1.0`. With the `rayon` feature, the work is split into batches
+    /// that run in parallel, and the callback is only called between batches
+    /// on the thread that called `render`, never concurrently. The output does
+    /// not depend on whether a callback is set.
+    ///
+    /// ```
+    /// use larnt::{Scene, Sphere, Vector};
+    ///
+    /// let mut scene = Scene::new();
+    /// for i in 0..10 {
+    ///     scene.add(Sphere::builder(Vector::new(i as f64 * 2.0, 0.0, 0.0), 1.0).build());
+    /// }
+    ///
+    /// let mut fractions = Vec::new();
+    /// let eye = Vector::new(8.0, -20.0, 5.0);
+    /// let paths = scene.render().eye(eye).width(256.0).height(256.0).on_progress(|f| fractions.push(f)).call();
+    ///
+    /// assert!(fractions.len() > 2);
+    /// assert!(fractions.windows(2).all(|w| w[0] < w[1]));
+    /// assert_eq!(fractions.last(), Some(&1.0));
+    ///
+    /// let plain = scene.render().eye(eye).width(256.0).height(256.0).call();
+    /// assert!(paths.iter_paths().eq(plain.iter_paths()));
+    /// ```
+    ///
+    /// # Silhouette
+    ///
+    /// With `silhouette`, the textures of the shapes are ignored and only the
+    /// outlines of the scene as seen from the eye are drawn: the boundaries
+    /// between shapes and the background, between overlapping shapes, and
+    /// where a shape occludes another part of itself. They are found by
+    /// casting rays on a grid with a spacing of two pixels and refining the
+    /// boundary between differing rays, so details smaller than the grid can
+    /// be missed.
+    ///
+    /// ```
+    /// use larnt::{Cube, CubeTexture, Scene, Sphere, SphereTexture, Vector};
+    ///
+    /// let mut scene = Scene::new();
+    /// scene.add(Sphere::builder(Vector::new(0.0, 0.0, 0.0), 1.0).texture(SphereTexture::lat_lng().call()).build());
+    /// scene.add(
+    ///     Cube::builder(Vector::new(0.5, -0.5, -0.5), Vector::new(1.5, 0.5, 0.5))
+    ///         .texture(CubeTexture::striped().stripes(8).call())
+    ///         .build(),
+    /// );
+    ///
+    /// let eye = Vector::new(0.0, -5.0, 0.0);
+    /// let paths = scene.render().eye(eye).width(256.0).height(256.0).silhouette(true).call();
+    ///
+    /// // All points lie on the outline of the union, none on the textures
+    /// // inside it (the outline of the sphere is 56 pixels from the center)
+    /// let center = Vector::new(128.0, 128.0, 0.0);
+    /// let points: Vec<_> = paths.iter_paths().flatten().collect();
+    /// assert!(points.len() > 100);
+    /// assert!(points.iter().all(|p| Vector::new(p.x, p.y, 0.0).distance(center) > 50.0));
+    /// ```
+    #[builder]
+    pub fn render<'p>(
+        &self,
+        eye: Vector,
+        #[builder(default = Vector::new(0.0, 0.0, 0.0))] center: Vector,
+        #[builder(default = Vector::new(0.0, 0.0, 1.0))] up: Vector,
+        #[builder(default)] roll: f64,
+        #[builder(default = 1024.0)] width: f64,
+        #[builder(default = 1024.0)] height: f64,
+        #[builder(default = 50.0)] fovy: f64,
+        #[builder(default = 0.1)] near: f64,
+        #[builder(default = 1e3)] far: f64,
+        step: Option<f64>,
+        #[builder(default, with = || true)] step_auto: bool,
+        #[builder(default = 2.0)] min_arc_angle: f64,
+        #[builder(default)] linear_depth: bool,
+        #[builder(default)] projection: Projection,
+        join: Option<f64>,
+        simplify: Option<f64>,
+        #[builder(default)] shading: Shading,
+        light: Option<Vector>,
+        #[builder(default)] silhouette: bool,
+        #[builder(with = |f: impl FnMut(f64) + 'p| Box::new(f) as Box<dyn FnMut(f64) + 'p>)]
+        on_progress: Option<Box<dyn FnMut(f64) + 'p>>,
+    ) -> Paths<Vector> {
+        let camera = Camera {
+            eye,
+            center,
+            up,
+            roll,
+            width,
+            height,
+            fovy,
+            near,
+            far,
+            step,
+            step_auto,
+            min_arc_angle,
+            linear_depth,
+            projection,
+            join,
+            simplify,
+            shading,
+            light,
+        };
+        let mut progress = Progress::new(on_progress);
+        self.render_view(&camera, silhouette, &mut progress)
+    }
+
+    /// Renders a stereo pair of the scene, returning the paths seen by the
+    /// left and the right eye.
+    ///
+    /// Takes the same options as [`Scene::render`], except `on_progress`,
+    /// plus the interpupillary distance `ipd`, the distance between the eyes
+    /// in scene units. The eyes are moved apart from `eye` along the right
+    /// vector of the camera, the cross product of the view direction
+    /// `center - eye` and the rolled `up`, and both look at `center`. Shapes at the
+    /// distance of `center` appear at the same place in both images, nearer
+    /// shapes in front of the screen. A separation of about 1/30 of the
+    /// distance to `center` is comfortable.
+    ///
+    /// For parallel viewing, place the left image on the left, for cross-eyed
+    /// viewing on the right.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Cube, Matrix, Scene, Vector};
+    ///
+    /// let mut scene = Scene::new();
+    /// scene.add(Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build());
+    ///
+    /// let (width, height) = (512.0, 512.0);
+    /// let (left, right) = scene
+    ///     .render_stereo()
+    ///     .eye(Vector::new(6.0, 0.0, 2.0))
+    ///     .ipd(0.2)
+    ///     .width(width)
+    ///     .height(height)
+    ///     .call();
+    ///
+    /// // The near corner of the cube appears further right to the left eye
+    /// let max_x = |paths: &larnt::Paths<Vector>| paths.bounding_box().max.x;
+    /// assert!(max_x(&left) > max_x(&right));
+    ///
+    /// // A side-by-side image for cross-eyed viewing
+    /// let mut pair = right;
+    /// pair.extend(left.transform(&Matrix::translate(Vector::new(width, 0.0, 0.0))));
+    /// assert!(max_x(&pair) > width);
+    /// ```
+    #[builder]
+    pub fn render_stereo(
+        &self,
+        eye: Vector,
+        #[builder(default = Vector::new(0.0, 0.0, 0.0))] center: Vector,
+        #[builder(default = Vector::new(0.0, 0.0, 1.0))] up: Vector,
+        #[builder(default)] roll: f64,
+        ipd: f64,
+        #[builder(default = 1024.0)] width: f64,
+        #[builder(default = 1024.0)] height: f64,
+        #[builder(default = 50.0)] fovy: f64,
+        #[builder(default = 0.1)] near: f64,
+        #[builder(default = 1e3)] far: f64,
+        step: Option<f64>,
+        #[builder(default, with = || true)] step_auto: bool,
+        #[builder(default = 2.0)] min_arc_angle: f64,
+        #[builder(default)] linear_depth: bool,
+        #[builder(default)] projection: Projection,
+        join: Option<f64>,
+        simplify: Option<f64>,
+        #[builder(default)] shading: Shading,
+        light: Option<Vector>,
+        #[builder(default)] silhouette: bool,
+    ) -> (Paths<Vector>, Paths<Vector>) {
+        let up = roll_up(eye, center, up, roll);
+        let (_, right, _) = camera_basis(eye, center, up);
+        let offset = right.mul_scalar(ipd / 2.0);
+        let [left, right] = [eye.sub(offset), eye.add(offset)].map(|eye| {
+            let camera = Camera {
+                eye,
+                center,
+                up,
+                roll: 0.0,
+                width,
+                height,
+                fovy,
+                near,
+                far,
+                step,
+                step_auto,
+                min_arc_angle,
+                linear_depth,
+                projection,
+                join,
+                simplify,
+                shading,
+                light,
+            };
+            self.render_view(&camera, silhouette, &mut Progress::new(None))
+        });
+        (left, right)
+    }
+
+    /// Renders a turntable animation of the scene, returning the paths of
+    /// `frames` views evenly spaced around a full turn.
+    ///
+    /// Takes the same options as [`Scene::render`], except `on_progress`,
+    /// plus the number of `frames` and the `axis` of the turn (default: `up`, before the `roll`).
+    /// The eye, and the up vector with it, rotate around the line through
+    /// `center` along `axis`, starting at `eye` for the first frame. The
+    /// shapes stay in place, so every frame reuses the tree and the cached
+    /// paths of the scene instead of rebuilding them as rotating the shapes
+    /// would. Frames are rendered lazily as the iterator is advanced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Cube, Scene, Vector};
+    ///
+    /// let mut scene = Scene::new();
+    /// scene.add(Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build());
+    ///
+    /// let eye = Vector::new(4.0, 3.0, 2.0);
+    /// let frames: Vec<_> = scene.render_turntable().eye(eye).frames(8).call().collect();
+    /// assert_eq!(frames.len(), 8);
+    ///
+    /// // The first frame is the plain view from `eye`, a quarter turn later
+    /// // the cube looks the same again
+    /// let plain = scene.render().eye(eye).call();
+    /// let points = |paths: &larnt::Paths<Vector>| paths.iter_paths().flatten().count();
+    /// assert_eq!(points(&frames[0]), points(&plain));
+    /// assert!((frames[0].bounding_box().size().x - frames[2].bounding_box().size().x).abs() < 1.0);
+    /// ```
+    #[builder]
+    pub fn render_turntable(
+        &self,
+        eye: Vector,
+        #[builder(default = Vector::new(0.0, 0.0, 0.0))] center: Vector,
+        #[builder(default = Vector::new(0.0, 0.0, 1.0))] up: Vector,
+        #[builder(default)] roll: f64,
+        axis: Option<Vector>,
+        frames: usize,
+        #[builder(default = 1024.0)] width: f64,
+        #[builder(default = 1024.0)] height: f64,
+        #[builder(default = 50.0)] fovy: f64,
+        #[builder(default = 0.1)] near: f64,
+        #[builder(default = 1e3)] far: f64,
+        step: Option<f64>,
+        #[builder(default, with = || true)] step_auto: bool,
+        #[builder(default = 2.0)] min_arc_angle: f64,
+        #[builder(default)] linear_depth: bool,
+        #[builder(default)] projection: Projection,
+        join: Option<f64>,
+        simplify: Option<f64>,
+        #[builder(default)] shading: Shading,
+        light: Option<Vector>,
+        #[builder(default)] silhouette: bool,
+    ) -> impl Iterator<Item = Paths<Vector>> {
+        let axis = axis.unwrap_or(up);
+        let up = roll_up(eye, center, up, roll);
+        (0..frames).map(move |i| {
+            let rotation = Matrix::rotate(axis, i as f64 / frames as f64 * 2.0 * PI);
+            let camera = Camera {
+                eye: center.add(rotation.mul_vector(eye.sub(center))),
+                center,
+                up: rotation.mul_vector(up),
+                roll: 0.0,
+                width,
+                height,
+                fovy,
+                near,
+                far,
+                step,
+                step_auto,
+                min_arc_angle,
+                linear_depth,
+                projection,
+                join,
+                simplify,
+                shading,
+                light,
+            };
+            self.render_view(&camera, silhouette, &mut Progress::new(None))
+        })
+    }
+
+    fn render_view(
+        &self,
+        camera: &Camera,
+        silhouette: bool,
+        progress: &mut Progress,
+    ) -> Paths<Vector> {
+        if !camera.has_area() {
+            progress.finish();
+            return Paths::new();
+        }
+        let view = View::new(camera, || self.bounding_box());
+        let args = view.args();
+
+        let compiled = self.compiled.get_or_init(|| {
+            let entries: Vec<_> = self.shapes.iter().zip(self.roles.iter().copied()).collect();
+            Compiled {
+                tree: Tree::new(
+                    entries
+                        .iter()
+                        .filter(|(_, role)| role.occludes())
+                        .map(|(shape, _)| Arc::clone(shape))
+                        .collect(),
+                ),
+                // Shapes that are not drawn cache no paths at all
+                paths: SCENE_EXEC.map(&(), &entries, |_, (shape, role)| {
+                    if role.drawn() {
+                        (!shape.view_dependent()).then(|| shape.paths(&args))
+                    } else {
+                        Some(Paths::new())
+                    }
+                }),
+            }
+        });
+
+        if silhouette {
+            return view.silhouette(&compiled.tree, progress, SCENE_EXEC);
+        }
+
+        let cached: Vec<_> = self.shapes.iter().zip(&compiled.paths).collect();
+        let fresh = progress.map(SCENE_EXEC, &(), &cached, 0.1, |_, (shape, cached)| {
+            cached.is_none().then(|| shape.paths(&args))
+        });
+
+        let mut paths = Paths::new();
+        for ((_, cached), fresh) in cached.into_iter().zip(fresh) {
+            paths.extend(fresh.or_else(|| cached.clone()).unwrap_or_default());
+        }
+
+        view.project(paths, &compiled.tree, progress, SCENE_EXEC)
+    }
+}
+
+/// How [`render`] maps the view from the eye to the canvas.
+///
+/// The panoramic projections see in every direction around the eye, oriented
+/// by `center` and `up`. Points closer than `near` or farther than `far` from
+/// the eye are clipped, and with `linear_depth` the z coordinate is the
+/// distance from the eye.
+///
+/// Shapes still tessellate their curves for the perspective view through
+/// [`RenderArgs::screen_mat`], which can leave curves behind the camera
+/// coarser than `step`.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Cube, Projection, Vector, render};
+///
+/// // A ring of cubes around the eye
+/// let cubes: Vec<_> = (0..12)
+///     .map(|i| {
+///         let a = i as f64 / 12.0 * std::f64::consts::TAU;
+///         let p = Vector::new(5.0 * a.cos(), 5.0 * a.sin(), 0.0);
+///         Cube::builder(p.sub_scalar(0.5), p.add_scalar(0.5)).build()
+///     })
+///     .collect();
+///
+/// let (width, height) = (1024.0, 512.0);
+/// let paths = render(cubes)
+///     .eye(Vector::new(0.0, 0.0, 0.0))
+///     .center(Vector::new(1.0, 0.0, 0.0))
+///     .width(width)
+///     .height(height)
+///     .projection(Projection::Equirectangular)
+///     .call();
+///
+/// // The cubes are spread over the full width and none is drawn across the
+/// // seam behind the eye
+/// let bx = paths.bounding_box();
+/// assert!(bx.min.x < 100.0 && bx.max.x > width - 100.0);
+/// assert!(bx.min.y > 0.0 && bx.max.y < height);
+/// for path in paths.iter_paths() {
+///     assert!(path.windows(2).all(|w| (w[0].x - w[1].x).abs() < width / 2.0));
+/// }
+/// ```
+///
+/// Looking straight down with a stereographic projection wraps the same ring
+/// around the center of the canvas:
+///
+/// ```
+/// use larnt::{Cube, Projection, Vector, render};
+///
+/// let cubes: Vec<_> = (0..12)
+///     .map(|i| {
+///         let a = i as f64 / 12.0 * std::f64::consts::TAU;
+///         let p = Vector::new(5.0 * a.cos(), 5.0 * a.sin(), 0.0);
+///         Cube::builder(p.sub_scalar(0.5), p.add_scalar(0.5)).build()
+///     })
+///     .collect();
+///
+/// let paths = render(cubes)
+///     .eye(Vector::new(0.0, 0.0, 0.0))
+///     .center(Vector::new(0.0, 0.0, -1.0))
+///     .up(Vector::new(1.0, 0.0, 0.0))
+///     .fovy(270.0)
+///     .width(512.0)
+///     .height(512.0)
+///     .projection(Projection::Stereographic)
+///     .call();
+///
+/// // The horizon is 106 pixels from the center
+/// let center = Vector::new(256.0, 256.0, 0.0);
+/// for p in paths.iter_paths().flatten() {
+///     let r = Vector::new(p.x, p.y, 0.0).distance(center);
+///     assert!(r > 80.0 && r < 140.0);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Projection {
+    /// A perspective frustum with the vertical field of view `fovy`.
+    #[default]
+    Perspective,
+    /// An equirectangular panorama: the width spans the longitudes from -180°
+    /// to 180° around `up`, with the view direction in the middle, and the
+    /// height spans the latitudes from -90° to 90°. Paths crossing the seam
+    /// behind the eye are split at the left and right edges. Use a canvas
+    /// twice as wide as it is high for an undistorted panorama. `fovy` is
+    /// ignored.
+    Equirectangular,
+    /// A stereographic projection through a sphere around the eye onto a
+    /// plane, the "little planet" look when looking down. The view direction
+    /// is in the middle and `fovy` is the field of view across the height,
+    /// which can exceed 180°, up to the direction behind the eye, which is
+    /// infinitely far away.
+    Stereographic,
+}
+
+/// Reusable camera settings for [`Scene::render_with`].
+///
+/// Holds the same options as [`Scene::render`], except `silhouette` and
+/// `on_progress`, with the same defaults. The fields are public, so a camera
+/// can be moved between renders with struct update syntax.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Camera, Cube, Scene, Vector};
+///
+/// let mut scene = Scene::new();
+/// scene.add(Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build());
+///
+/// let camera = Camera::builder(Vector::new(4.0, 3.0, 2.0))
+///     .width(512.0)
+///     .height(512.0)
+///     .fovy(40.0)
+///     .build();
+/// let paths = scene.render_with(&camera);
+/// let same = scene.render().eye(camera.eye).width(512.0).height(512.0).fovy(40.0).call();
+/// assert_eq!(paths.len(), same.len());
+///
+/// // The same settings from the other side
+/// let back = scene.render_with(&Camera {
+///     eye: Vector::new(-4.0, -3.0, 2.0),
+///     ..camera
+/// });
+/// assert!(!back.is_empty());
+/// ```
+///
+/// Every option of a camera is also an option of the [`render`] and
+/// [`Scene::render`] builders, and renders the same paths either way:
+///
+/// ```
+/// use larnt::{Camera, Cube, Scene, Shading, Vector};
+///
+/// let mut scene = Scene::new();
+/// scene.add(Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build());
+/// let eye = Vector::new(4.0, 3.0, 2.0);
+/// let same = |a: larnt::Paths<Vector>, b: larnt::Paths<Vector>| a.iter_paths().eq(b.iter_paths());
+///
+/// let camera = Camera::builder(eye).linear_depth(true).build();
+/// assert!(same(scene.render_with(&camera), scene.render().eye(eye).linear_depth(true).call()));
+///
+/// let camera = Camera::builder(eye).width(64.0).height(64.0).step_auto().build();
+/// let builder = scene.render().eye(eye).width(64.0).height(64.0).step_auto().call();
+/// assert!(same(scene.render_with(&camera), builder));
+///
+/// let camera = Camera::builder(eye).roll(30.0).build();
+/// assert!(same(scene.render_with(&camera), scene.render().eye(eye).roll(30.0).call()));
+///
+/// let camera = Camera::builder(eye).min_arc_angle(30.0).build();
+/// assert!(same(scene.render_with(&camera), scene.render().eye(eye).min_arc_angle(30.0).call()));
+///
+/// let camera = Camera::builder(eye).join(1e-6).simplify(1e-3).build();
+/// let builder = scene.render().eye(eye).join(1e-6).simplify(1e-3).call();
+/// assert!(same(scene.render_with(&camera), builder));
+///
+/// let light = Vector::new(1.0, 0.0, 0.0);
+/// let camera = Camera::builder(eye).shading(Shading::Hatch).light(light).build();
+/// let builder = scene.render().eye(eye).shading(Shading::Hatch).light(light).call();
+/// assert!(same(scene.render_with(&camera), builder));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Builder)]
+pub struct Camera {
+    /// Camera position.
+    #[builder(start_fn)]
+    pub eye: Vector,
+    /// Point the camera looks at.
+    #[builder(default = Vector::new(0.0, 0.0, 0.0))]
+    pub center: Vector,
+    /// Up direction vector.
+    #[builder(default = Vector::new(0.0, 0.0, 1.0))]
+    pub up: Vector,
+    /// Camera roll in degrees, see [`render`].
+    #[builder(default)]
+    pub roll: f64,
+    /// Output width in pixels.
+    #[builder(default = 1024.0)]
+    pub width: f64,
+    /// Output height in pixels.
+    #[builder(default = 1024.0)]
+    pub height: f64,
+    /// Vertical field of view in degrees.
+    #[builder(default = 50.0)]
+    pub fovy: f64,
+    /// Near clipping plane distance.
+    #[builder(default = 0.1)]
+    pub near: f64,
+    /// Far clipping plane distance.
+    #[builder(default = 1e3)]
+    pub far: f64,
+    /// Path subdivision step size for visibility testing, in pixels.
+    pub step: Option<f64>,
+    /// Derive `step` from the scene and the output size, between 1/64 and 1/2
+    /// of a pixel, see [`render`].
+    #[builder(default, with = || true)]
+    pub step_auto: bool,
+    /// Angle at which curved outlines stop subdividing, see [`render`].
+    #[builder(default = 2.0)]
+    pub min_arc_angle: f64,
+    /// Output camera-space depth as the z coordinate.
+    #[builder(default)]
+    pub linear_depth: bool,
+    /// How the view is mapped to the canvas.
+    #[builder(default)]
+    pub projection: Projection,
+    /// Tolerance in pixels for joining the rendered paths, see [`render`].
+    pub join: Option<f64>,
+    /// Threshold in pixels for simplifying the rendered paths, see [`render`].
+    pub simplify: Option<f64>,
+    /// How lines are thinned out by the light, see [`render`].
+    #[builder(default)]
+    pub shading: Shading,
+    /// Direction toward the light for `shading`, toward the eye by default.
+    pub light: Option<Vector>,
+}
+
+impl Camera {
+    /// Returns the [`RenderArgs`] that shapes get for their
+    /// [`paths`](Shape::paths) when rendered by this camera.
+    ///
+    /// `step` defaults to one pixel, a `step_auto` step depends on the scene
+    /// and is not resolved here. Useful for calling [`Shape::paths`] directly,
+    /// for example in tests of a shape's texture.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Camera, Vector};
+    ///
+    /// let args = Camera::builder(Vector::new(4.0, 3.0, 2.0)).width(512.0).build().render_args();
+    /// assert_eq!((args.width, args.height, args.step), (512.0, 1024.0, 1.0));
+    /// let center = args.screen_mat.mul_position_w(Vector::new(0.0, 0.0, 0.0));
+    /// assert!((center.x - 256.0).abs() < 1e-9 && (center.y - 512.0).abs() < 1e-9);
+    /// ```
+    pub fn render_args(&self) -> RenderArgs {
+        let up = roll_up(self.eye, self.center, self.up, self.roll);
+        let matrix = Matrix::look_at(self.eye, self.center, up).with_perspective(
+            self.fovy,
+            self.width / self.height,
+            self.near,
+            self.far,
+        );
+        RenderArgs {
+            screen_mat: Matrix::viewport(self.width, self.height).mul(&matrix),
+            eye: self.eye,
+            up,
+            width: self.width,
+            height: self.height,
+            step: self.step.unwrap_or(1.0),
+            min_arc_angle: self.min_arc_angle,
+        }
     }
 
-    if step > 0.0 {
-        paths = paths.chop_adaptive(&args);
+    /// Whether the canvas covers any area, degenerate canvases render nothing.
+    fn has_area(&self) -> bool {
+        self.width > 0.0 && self.height > 0.0
     }
+}
 
-    let tree = Tree::new(shapes);
-    let filter = {
-        let visible = |eye: Vector, point: Vector| -> bool {
+/// How [`render`] shades the surfaces with their texture lines.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Shading {
+    /// Every line is drawn.
+    #[default]
+    None,
+    /// Lines are thinned out where the surface faces the light, so that
+    /// lit parts are sparse and shadowed parts keep their full texture, for a
+    /// tonal pencil look. Dense textures give the smoothest tones.
+    Hatch,
+}
+
+/// A [`Camera`] prepared for rendering, with the roll applied to `up` and
+/// the chop step chosen.
+struct View {
+    camera: Camera,
+    step: f64,
+}
+
+impl View {
+    /// Prepares `camera` for a scene bounded by `bx`, which is only computed
+    /// for `step_auto`.
+    fn new(camera: &Camera, bx: impl FnOnce() -> BBox) -> Self {
+        let mut view = View {
+            camera: Camera {
+                up: roll_up(camera.eye, camera.center, camera.up, camera.roll),
+                roll: 0.0,
+                ..*camera
+            },
+            step: 1.0,
+        };
+        view.step = match camera.step {
+            Some(step) => step,
+            None if camera.step_auto => view.auto_step(bx()),
+            None => 1.0,
+        };
+        view
+    }
+
+    fn matrix(&self) -> Matrix {
+        let c = &self.camera;
+        Matrix::look_at(c.eye, c.center, c.up).with_perspective(
+            c.fovy,
+            c.width / c.height,
+            c.near,
+            c.far,
+        )
+    }
+
+    fn args(&self) -> RenderArgs {
+        Camera {
+            step: Some(self.step),
+            ..self.camera
+        }
+        .render_args()
+    }
+
+    /// Chooses the chop step for a scene bounded by `bx`, see [`render`].
+    fn auto_step(&self, bx: BBox) -> f64 {
+        const STEP: f64 = 0.5;
+        const MIN_STEP: f64 = 1.0 / 64.0;
+        const STEPS: f64 = 512.0;
+
+        let (forward, _, _) = self.basis();
+        let mut screen = BBox::default();
+        for i in 0..8 {
+            let corner = bx.anchor(Vector::new(
+                (i & 1) as f64,
+                (i >> 1 & 1) as f64,
+                (i >> 2 & 1) as f64,
+            ));
+            let behind = corner.sub(self.camera.eye).dot(forward) <= 0.0;
+            if !corner.length().is_finite()
+                || behind && self.camera.projection == Projection::Perspective
+            {
+                return STEP;
+            }
+            let v = self.ndc(corner);
+            let v = Vector::new(
+                (v.x + 1.0) * self.camera.width / 2.0,
+                (v.y + 1.0) * self.camera.height / 2.0,
+                0.0,
+            );
+            screen = if i == 0 {
+                BBox::new(v, v)
+            } else {
+                screen.extend(BBox::new(v, v))
+            };
+        }
+        let size = screen.size();
+        let step = size.x.max(size.y) / STEPS;
+        if step.is_nan() {
+            STEP
+        } else {
+            step.clamp(MIN_STEP, STEP)
+        }
+    }
+
+    /// Removes the hidden parts of `paths` and projects them to screen space.
+    fn project<T: Shape, E: Exec<Tree<T>>>(
+        &self,
+        mut paths: Paths<Vector>,
+        tree: &Tree<T>,
+        progress: &mut Progress,
+        exec: E,
+    ) -> Paths<Vector> {
+        let viewport_mat = Matrix::viewport(self.camera.width, self.camera.height);
+        let visible = |tree: &Tree<T>, eye: Vector, point: Vector| -> bool {
             let v = eye.sub(point);
             if v.length() == 0.0 {
                 return true;
@@ -107,13 +1369,645 @@ pub fn render<T: Shape>(
             let hit = tree.intersect(r);
             hit.t >= v.length()
         };
-        ClipFilter::new(matrix, eye, visible)
+
+        paths = match self.camera.projection {
+            Projection::Perspective => {
+                paths = self.clip_near(&paths);
+                if self.step > 0.0 {
+                    paths = paths.chop_adaptive(&self.args());
+                }
+                paths = self.shade(paths, tree, exec);
+                let matrix = self.matrix();
+                filter_paths(exec, tree, &paths, progress, |tree, path, result| {
+                    let filter = ClipFilter::new(matrix, self.camera.eye, |eye, point| {
+                        visible(tree, eye, point)
+                    });
+                    path_filter(path, &filter, result);
+                })
+            }
+            Projection::Equirectangular | Projection::Stereographic => {
+                if self.step > 0.0 {
+                    paths = self.chop_projected(&paths);
+                }
+                paths = self.shade(paths, tree, exec);
+                let filtered = filter_paths(exec, tree, &paths, progress, |tree, path, result| {
+                    let filter = RangeFilter {
+                        view: self,
+                        visible: |eye, point| visible(tree, eye, point),
+                    };
+                    path_filter(path, &filter, result);
+                });
+                let mut projected = Paths::new();
+                for path in filtered.iter_paths() {
+                    self.split_seam(path, &mut projected);
+                }
+                projected
+            }
+        };
+
+        if self.step > 0.0 {
+            paths = paths.simplify(1e-6);
+        }
+
+        progress.finish();
+        self.depth(self.clean(paths.transform(&viewport_mat)))
+    }
+
+    /// Cuts `paths` at the near plane, keeping the parts in front of it.
+    ///
+    /// The perspective projection flips points behind the eye, so a segment
+    /// crossing the near plane would be chopped and projected as if it ran
+    /// off the other side of the canvas.
+    fn clip_near(&self, paths: &Paths<Vector>) -> Paths<Vector> {
+        let (forward, _, _) = self.basis();
+        // Slightly in front of the plane so the cut point survives projection
+        let near = self.camera.near * (1.0 + 1e-9);
+        let distance = |v: Vector| v.sub(self.camera.eye).dot(forward) - near;
+
+        let mut result = Paths::new();
+        for path in paths.iter_paths() {
+            let mut new_path = result.new_path();
+            if let [v] = path {
+                if distance(*v) >= 0.0 {
+                    new_path.push(*v);
+                }
+                continue;
+            }
+            for w in path.windows(2) {
+                let (a, b) = (w[0], w[1]);
+                let (da, db) = (distance(a), distance(b));
+                if da < 0.0 && db < 0.0 {
+                    continue;
+                }
+                let cut = a.add(b.sub(a).mul_scalar(da / (da - db)));
+                if da < 0.0 {
+                    drop(new_path);
+                    new_path = result.new_path();
+                    new_path.push(cut);
+                } else if new_path.is_empty() {
+                    new_path.push(a);
+                }
+                if db < 0.0 {
+                    new_path.push(cut);
+                    drop(new_path);
+                    new_path = result.new_path();
+                } else {
+                    new_path.push(b);
+                }
+            }
+        }
+        result
+    }
+
+    /// Thins out the chopped world space `paths` where the surface under them
+    /// faces the light, with [`Shading::Hatch`].
+    ///
+    /// Path `i` is ranked by the van der Corput sequence, which spreads the
+    /// ranks of any run of consecutive paths evenly over `[0, 1)`, and keeps
+    /// its points where the rank is at most `1 - max(0, n · l)`. The first
+    /// path, often an outline, is always kept.
+    fn shade<T: Shape, E: Exec<Tree<T>>>(
+        &self,
+        paths: Paths<Vector>,
+        tree: &Tree<T>,
+        exec: E,
+    ) -> Paths<Vector> {
+        if self.camera.shading == Shading::None {
+            return paths;
+        }
+        let light = self.camera.light.map(|l| l.normalize());
+        let lit = |tree: &Tree<T>, v: Vector| {
+            let d = v.sub(self.camera.eye);
+            let distance = d.length();
+            if distance == 0.0 {
+                return 0.0;
+            }
+            // The surface in front of the point, a hidden point is dropped by
+            // the visibility test anyway
+            let hit = tree.intersect(Ray::new(self.camera.eye, d.mul_scalar(1.0 / distance)));
+            let Some(n) = hit.normal.filter(|_| hit.t <= distance * (1.0 + 1e-3)) else {
+                return 0.0;
+            };
+            let n = if n.dot(d) > 0.0 {
+                n.mul_scalar(-1.0)
+            } else {
+                n
+            };
+            n.dot(light.unwrap_or(d.mul_scalar(-1.0 / distance)))
+                .max(0.0)
+        };
+
+        let indexed: Vec<_> = paths.iter_paths().enumerate().collect();
+        let shaded = exec.map(tree, &indexed, |tree, &(i, path)| {
+            let rank = (i as u64).reverse_bits() as f64 / 2.0_f64.powi(64);
+            let mut result = Paths::new();
+            let mut current = result.new_path();
+            for &v in path {
+                if rank <= 1.0 - lit(tree, v) {
+                    current.push(v);
+                } else {
+                    drop(current);
+                    current = result.new_path();
+                }
+            }
+            drop(current);
+            result
+        });
+
+        let mut result = Paths::new();
+        for paths in shaded {
+            result.extend(paths);
+        }
+        result
+    }
+
+    /// Applies the optional `join` and `simplify` to screen space `paths`.
+    fn clean(&self, mut paths: Paths<Vector>) -> Paths<Vector> {
+        // Joining first lets simplify drop the vertices at the joints
+        if let Some(tolerance) = self.camera.join {
+            paths = paths.join(tolerance);
+        }
+        if let Some(threshold) = self.camera.simplify {
+            paths = paths.simplify(threshold);
+        }
+        paths
+    }
+
+    /// Converts the z coordinate of screen space `paths` to camera-space depth
+    /// with `linear_depth`.
+    fn depth(&self, paths: Paths<Vector>) -> Paths<Vector> {
+        if !self.camera.linear_depth {
+            return paths;
+        }
+        // Invert the perspective depth mapping of `Matrix::frustum`
+        let (n, f) = (self.camera.near, self.camera.far);
+        paths.map(|v| Vector::new(v.x, v.y, 2.0 * f * n / (f + n - v.z * (f - n))))
+    }
+
+    fn basis(&self) -> (Vector, Vector, Vector) {
+        camera_basis(self.camera.eye, self.camera.center, self.camera.up)
+    }
+
+    /// Projects `v` to normalized device coordinates.
+    fn ndc(&self, v: Vector) -> Vector {
+        let (f, s, u) = self.basis();
+        let d = v.sub(self.camera.eye);
+        let r = d.length();
+        let (n, far) = (self.camera.near, self.camera.far);
+        // The depth mapping of `Matrix::frustum` applied to the distance
+        let z = (far + n - 2.0 * far * n / r) / (far - n);
+        match self.camera.projection {
+            Projection::Perspective => self.matrix().mul_position_w(v),
+            Projection::Equirectangular => Vector::new(
+                d.dot(s).atan2(d.dot(f)) / PI,
+                (d.dot(u) / r).clamp(-1.0, 1.0).asin() / FRAC_PI_2,
+                z,
+            ),
+            Projection::Stereographic => {
+                // The point at `tan(θ / 2)` from the center, `θ` away from `f`
+                let k = radians(self.camera.fovy / 4.0).tan() * (r + d.dot(f));
+                Vector::new(
+                    d.dot(s) / (k * self.camera.width / self.camera.height),
+                    d.dot(u) / k,
+                    z,
+                )
+            }
+        }
+    }
+
+    /// Returns the direction of the ray from the eye through the screen point
+    /// `(x, y)`.
+    fn direction(&self, x: f64, y: f64) -> Vector {
+        let (f, s, u) = self.basis();
+        let (x, y) = (
+            2.0 * x / self.camera.width - 1.0,
+            2.0 * y / self.camera.height - 1.0,
+        );
+        match self.camera.projection {
+            Projection::Perspective => {
+                let tan_y = radians(self.camera.fovy / 2.0).tan();
+                let tan_x = tan_y * self.camera.width / self.camera.height;
+                f.add(s.mul_scalar(x * tan_x))
+                    .add(u.mul_scalar(y * tan_y))
+                    .normalize()
+            }
+            Projection::Equirectangular => {
+                let (lng, lat) = (x * PI, y * FRAC_PI_2);
+                f.mul_scalar(lat.cos() * lng.cos())
+                    .add(s.mul_scalar(lat.cos() * lng.sin()))
+                    .add(u.mul_scalar(lat.sin()))
+            }
+            Projection::Stereographic => {
+                let k = radians(self.camera.fovy / 4.0).tan();
+                let (x, y) = (x * k * self.camera.width / self.camera.height, y * k);
+                let rho = x.hypot(y);
+                if rho == 0.0 {
+                    return f;
+                }
+                let theta = 2.0 * rho.atan();
+                f.mul_scalar(theta.cos())
+                    .add(s.mul_scalar(x / rho * theta.sin()))
+                    .add(u.mul_scalar(y / rho * theta.sin()))
+            }
+        }
+    }
+
+    /// Chops `paths` until consecutive points are at most `step` pixels apart
+    /// after the projection, leaving segments outside of the canvas whole.
+    fn chop_projected(&self, paths: &Paths<Vector>) -> Paths<Vector> {
+        let scale = Vector::new(self.camera.width / 2.0, self.camera.height / 2.0, 0.0);
+        let pixel = |v: Vector| (v, self.ndc(v).mul(scale));
+        let step_sq = self.step.powi(2);
+
+        let mut result = Paths::new();
+        for path in paths.iter_paths() {
+            let mut new_path = result.new_path();
+            if let Some(&first) = path.first() {
+                new_path.push(first);
+            }
+            for w in path.windows(2) {
+                recursive_subdivide(
+                    (pixel(w[0]), pixel(w[1])),
+                    &|(a, _), (b, _)| pixel(a.add(b).mul_scalar(0.5)),
+                    &|(a, sa): (Vector, Vector), (b, sb): (Vector, Vector)| {
+                        let outside = |sa: f64, sb: f64, size: f64| {
+                            sa < -size && sb < -size || sa > size && sb > size
+                        };
+                        outside(sa.x, sb.x, scale.x)
+                            || outside(sa.y, sb.y, scale.y)
+                            || sa.distance_squared(sb) < step_sq
+                            || a.distance_squared(b) < EPS
+                            || !a.distance_squared(b).is_finite()
+                    },
+                    &mut |(v, _)| new_path.push(v),
+                );
+            }
+        }
+        result
+    }
+
+    /// Projects a path of visible world points to normalized device
+    /// coordinates, splitting it where it wraps around the seam behind the
+    /// eye.
+    fn split_seam(&self, path: &[Vector], result: &mut Paths<Vector>) {
+        if self.camera.projection != Projection::Equirectangular {
+            result.new_path().extend(path.iter().map(|&v| self.ndc(v)));
+            return;
+        }
+        let (_, s, _) = self.basis();
+        let mut new_path = result.new_path();
+        let mut points = path.iter().map(|&v| (v, self.ndc(v))).peekable();
+        while let Some((a, pa)) = points.next() {
+            new_path.push(pa);
+            let Some(&(b, pb)) = points.peek() else {
+                break;
+            };
+            if (pa.x - pb.x).abs() <= 1.0 {
+                continue;
+            }
+            // The seam is where the right coordinate changes its sign
+            let (da, db) = (a.sub(self.camera.eye).dot(s), b.sub(self.camera.eye).dot(s));
+            let t = if da == db { 0.5 } else { da / (da - db) };
+            let seam = self.ndc(a.add(b.sub(a).mul_scalar(t)));
+            new_path.push(Vector::new(pa.x.signum(), seam.y, seam.z));
+            drop(new_path);
+            new_path = result.new_path();
+            new_path.push(Vector::new(pb.x.signum(), seam.y, seam.z));
+        }
+    }
+
+    /// Traces the outlines of the shapes in `tree` in screen space.
+    fn silhouette<T: Shape + Sync, E: Exec<()>>(
+        &self,
+        tree: &Tree<T>,
+        progress: &mut Progress,
+        exec: E,
+    ) -> Paths<Vector> {
+        const CELL: f64 = 2.0;
+        const REFINE: usize = 8;
+
+        let sample = |x: f64, y: f64| -> Sample {
+            tree.intersect_index(Ray::new(self.camera.eye, self.direction(x, y)))
+                .map(|(hit, index)| (index, hit.t))
+        };
+
+        // A depth jump between rays on the same shape is a fold of the
+        // surface if it does not shrink when the rays are moved closer
+        let jump = |a: Sample, b: Sample| match (a, b) {
+            (Some((_, ta)), Some((_, tb))) => (ta - tb).abs() > 0.02 * ta.min(tb),
+            _ => false,
+        };
+        let differ = |a: Sample, b: Sample| match (a, b) {
+            (None, None) => false,
+            (Some((ia, _)), Some((ib, _))) if ia == ib => jump(a, b),
+            _ => true,
+        };
+        let is_fold = |mut pa: (f64, f64), mut a: Sample, mut pb: (f64, f64), mut b: Sample| {
+            let depth = |s: Sample| s.map_or(0.0, |(_, t)| t);
+            let initial = (depth(a) - depth(b)).abs();
+            for _ in 0..4 {
+                let pm = ((pa.0 + pb.0) / 2.0, (pa.1 + pb.1) / 2.0);
+                let m = sample(pm.0, pm.1);
+                if m.map(|(i, _)| i) != a.map(|(i, _)| i) || m.map(|(i, _)| i) != b.map(|(i, _)| i)
+                {
+                    return true;
+                }
+                if (depth(a) - depth(m)).abs() > (depth(m) - depth(b)).abs() {
+                    (pb, b) = (pm, m);
+                } else {
+                    (pa, a) = (pm, m);
+                }
+            }
+            (depth(a) - depth(b)).abs() > initial / 2.0
+        };
+        let cut = |pa: (f64, f64), a: Sample, pb: (f64, f64), b: Sample| match (a, b) {
+            (Some((ia, _)), Some((ib, _))) if ia == ib => jump(a, b) && is_fold(pa, a, pb, b),
+            _ => differ(a, b),
+        };
+
+        let nx = (self.camera.width / CELL).ceil() as usize;
+        let ny = (self.camera.height / CELL).ceil() as usize;
+        let position = |i: usize, j: usize| (i as f64 * CELL, j as f64 * CELL);
+        let rows: Vec<usize> = (0..=ny).collect();
+        let grid: Vec<Vec<Sample>> = progress.map(exec, &(), &rows, 0.4, |_, &j| {
+            (0..=nx)
+                .map(|i| {
+                    let (x, y) = position(i, j);
+                    sample(x, y)
+                })
+                .collect()
+        });
+
+        // Marching squares over the cut grid edges, keyed by their lower grid
+        // point and direction (`0` along x, `1` along y, `2` for the cell center)
+        let cells: Vec<(usize, usize)> =
+            (0..ny).flat_map(|j| (0..nx).map(move |i| (i, j))).collect();
+        let crossings = progress.map(exec, &(), &cells, 0.8, |_, &(i, j)| {
+            let edges = [
+                ((i, j), (i + 1, j), (i, j, 0)),
+                ((i + 1, j), (i + 1, j + 1), (i + 1, j, 1)),
+                ((i, j + 1), (i + 1, j + 1), (i, j + 1, 0)),
+                ((i, j), (i, j + 1), (i, j, 1)),
+            ];
+            edges
+                .into_iter()
+                .filter(|&((ia, ja), (ib, jb), _)| {
+                    cut(
+                        position(ia, ja),
+                        grid[ja][ia],
+                        position(ib, jb),
+                        grid[jb][ib],
+                    )
+                })
+                .map(|(_, _, key)| key)
+                .collect::<Vec<_>>()
+        });
+        let mut segments: Paths<(usize, usize, u8)> = Paths::new();
+        for (&(i, j), crossing) in cells.iter().zip(crossings) {
+            match crossing.len() {
+                0 => {}
+                2 => segments.new_path().extend(crossing),
+                _ => {
+                    for key in crossing {
+                        segments.new_path().extend([key, (i, j, 2)]);
+                    }
+                }
+            }
+        }
+        let segments = segments.splice_exact();
+
+        // Each point is placed on the nearer surface at the boundary, found by
+        // bisecting the cut grid edge
+        let point = |(i, j, dir): (usize, usize, u8)| -> Option<Vector> {
+            let (x, y) = position(i, j);
+            let (mut pa, mut pb) = match dir {
+                0 => ((x, y), (x + CELL, y)),
+                1 => ((x, y), (x, y + CELL)),
+                _ => (
+                    (x + CELL / 2.0, y + CELL / 2.0),
+                    (x + CELL / 2.0, y + CELL / 2.0),
+                ),
+            };
+            let (mut a, mut b) = (sample(pa.0, pa.1), sample(pb.0, pb.1));
+            if dir < 2 {
+                for _ in 0..REFINE {
+                    let pm = ((pa.0 + pb.0) / 2.0, (pa.1 + pb.1) / 2.0);
+                    let m = sample(pm.0, pm.1);
+                    if differ(a, m) {
+                        (pb, b) = (pm, m);
+                    } else {
+                        (pa, a) = (pm, m);
+                    }
+                }
+            } else if a.is_none() {
+                // A junction in the background takes the depth of its corners
+                a = [(0, 0), (1, 0), (0, 1), (1, 1)]
+                    .into_iter()
+                    .filter_map(|(di, dj)| grid[j + dj][i + di])
+                    .min_by(|a, b| a.1.total_cmp(&b.1));
+            }
+            let (p, (_, t)) = match (a, b) {
+                (Some(a), Some(b)) if b.1 < a.1 => (pb, b),
+                (Some(a), _) => (pa, a),
+                (None, Some(b)) => (pb, b),
+                (None, None) => return None,
+            };
+            let world = self.camera.eye.add(self.direction(p.0, p.1).mul_scalar(t));
+            Some(Vector::new(p.0, p.1, self.ndc(world).z))
+        };
+
+        let keys: Vec<&[(usize, usize, u8)]> = segments.iter_paths().collect();
+        let mut paths = Paths::new();
+        for points in progress.map(exec, &(), &keys, 1.0, |_, keys| {
+            keys.iter()
+                .filter_map(|&key| point(key))
+                .collect::<Vec<_>>()
+        }) {
+            if points.len() >= 2 {
+                paths.new_path().extend(points);
+            }
+        }
+        progress.finish();
+        self.depth(self.clean(paths.simplify(1e-6)))
+    }
+}
+
+/// Rotates `up` clockwise, as seen from `eye`, by `roll` degrees about the
+/// view direction from `eye` to `center`.
+fn roll_up(eye: Vector, center: Vector, up: Vector, roll: f64) -> Vector {
+    if roll == 0.0 {
+        return up;
+    }
+    // `Matrix::rotate` turns counterclockwise looking along the axis
+    Matrix::rotate(center.sub(eye), radians(-roll)).mul_vector(up)
+}
+
+/// Returns the forward, right and up unit vectors of a camera, as in
+/// [`Matrix::look_at`].
+pub(crate) fn camera_basis(eye: Vector, center: Vector, up: Vector) -> (Vector, Vector, Vector) {
+    let f = center.sub(eye).normalize();
+    let s = f.cross(up.normalize());
+    let s = if s.length() == 0.0 {
+        Vector::new(up.z, up.x, up.y).normalize()
+    } else {
+        s.normalize()
     };
-    paths = paths.filter(&filter);
+    (f, s, s.cross(f).normalize())
+}
 
-    if step > 0.0 {
-        paths = paths.simplify(1e-6);
+/// Keeps the visible points on the canvas between the near and far distance
+/// from the eye, without projecting them.
+struct RangeFilter<'a, F> {
+    view: &'a View,
+    visible: F,
+}
+
+impl<F: Fn(Vector, Vector) -> bool> Filter for RangeFilter<'_, F> {
+    fn filter(&self, v: Vector) -> Option<Vector> {
+        let r = v.distance(self.view.camera.eye);
+        let p = self.view.ndc(v);
+        // Directions projected to infinity are not finite
+        let on_canvas = p.x.abs() <= 1.0 && p.y.abs() <= 1.0;
+        (on_canvas
+            && r >= self.view.camera.near
+            && r <= self.view.camera.far
+            && (self.visible)(self.view.camera.eye, v))
+        .then_some(v)
     }
+}
 
-    paths.transform(&viewport_mat)
+/// Filters `paths` in batches reported to `progress`.
+///
+/// `filter` adds the filtered parts of a path to the result, with a filter
+/// built from the shared `context`.
+fn filter_paths<C: ?Sized, E: Exec<C>>(
+    exec: E,
+    context: &C,
+    paths: &Paths<Vector>,
+    progress: &mut Progress,
+    filter: impl Fn(&C, &[Vector], &mut Paths<Vector>) + Send + Sync,
+) -> Paths<Vector> {
+    let paths: Vec<_> = paths.iter_paths().collect();
+    let mut result = Paths::new();
+    for filtered in progress.map(exec, context, &paths, 1.0, |context, path| {
+        let mut result = Paths::new();
+        filter(context, path, &mut result);
+        result
+    }) {
+        result.extend(filtered);
+    }
+    result
+}
+
+/// The shape index and distance of a ray hit, `None` for a miss.
+type Sample = Option<(usize, f64)>;
+
+/// Reports the progress of a render to an optional callback.
+///
+/// The work is a sequence of stages, each covering the fractions from where
+/// the previous one ended up to its own end.
+struct Progress<'p> {
+    callback: Option<Box<dyn FnMut(f64) + 'p>>,
+    done: f64,
 }
+
+impl<'p> Progress<'p> {
+    /// The number of batches each stage is split into for reporting.
+    const BATCHES: usize = 100;
+
+    fn new(callback: Option<Box<dyn FnMut(f64) + 'p>>) -> Self {
+        Progress {
+            callback,
+            done: 0.0,
+        }
+    }
+
+    fn report(&mut self, done: f64) {
+        if let Some(callback) = &mut self.callback
+            && done > self.done
+        {
+            self.done = done;
+            callback(done);
+        }
+    }
+
+    /// Runs a stage like [`Exec::map`], reporting progress up to `end`.
+    fn map<C: ?Sized, I: Sync, R: Send, E: Exec<C>>(
+        &mut self,
+        exec: E,
+        context: &C,
+        items: &[I],
+        end: f64,
+        f: impl Fn(&C, &I) -> R + Send + Sync,
+    ) -> Vec<R> {
+        if self.callback.is_none() {
+            return exec.map(context, items, f);
+        }
+        let start = self.done;
+        let mut results = Vec::with_capacity(items.len());
+        for batch in items.chunks(items.len().div_ceil(Self::BATCHES).max(1)) {
+            results.extend(exec.map(context, batch, &f));
+            self.report(start + (end - start) * results.len() as f64 / items.len() as f64);
+        }
+        self.report(end);
+        results
+    }
+
+    fn finish(&mut self) {
+        self.report(1.0);
+    }
+}
+
+/// Runs the stages of a render, on the calling thread or in parallel.
+///
+/// The items of a stage are mapped together with a shared `context`, which
+/// only has to be `Sync` when it runs in parallel. This keeps the shapes of
+/// [`render`] free of thread-safety bounds with the `rayon` feature.
+trait Exec<C: ?Sized>: Copy {
+    /// Applies `f` to the context and every item, returning the results in order.
+    fn map<I: Sync, R: Send>(
+        self,
+        context: &C,
+        items: &[I],
+        f: impl Fn(&C, &I) -> R + Send + Sync,
+    ) -> Vec<R>;
+}
+
+/// Runs every stage on the calling thread.
+#[derive(Clone, Copy)]
+struct Serial;
+
+impl<C: ?Sized> Exec<C> for Serial {
+    fn map<I: Sync, R: Send>(
+        self,
+        context: &C,
+        items: &[I],
+        f: impl Fn(&C, &I) -> R + Send + Sync,
+    ) -> Vec<R> {
+        items.iter().map(|item| f(context, item)).collect()
+    }
+}
+
+/// Runs the stages on the rayon thread pool.
+#[cfg(feature = "rayon")]
+#[derive(Clone, Copy)]
+struct Parallel;
+
+#[cfg(feature = "rayon")]
+impl<C: ?Sized + Sync> Exec<C> for Parallel {
+    fn map<I: Sync, R: Send>(
+        self,
+        context: &C,
+        items: &[I],
+        f: impl Fn(&C, &I) -> R + Send + Sync,
+    ) -> Vec<R> {
+        use rayon::prelude::*;
+        items.par_iter().map(|item| f(context, item)).collect()
+    }
+}
+
+/// How a [`Scene`], whose shapes are always `Send + Sync`, runs its stages.
+#[cfg(feature = "rayon")]
+const SCENE_EXEC: Parallel = Parallel;
+#[cfg(not(feature = "rayon"))]
+const SCENE_EXEC: Serial = Serial;
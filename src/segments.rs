@@ -0,0 +1,89 @@
+//! Line segment primitive.
+//!
+//! This module provides the [`Segments`] shape, a set of straight lines drawn
+//! as they are, e.g. the edges of graph and network diagrams where cylinders
+//! would be needlessly heavy.
+//!
+//! # Example
+//!
+//! ```
+//! use larnt::{Primitive, Segments, Sphere, Vector, render};
+//!
+//! // Two nodes joined by an edge
+//! let (a, b) = (Vector::new(-1.5, 0.0, 0.0), Vector::new(1.5, 0.0, 0.0));
+//! let shapes: Vec<Primitive> = vec![
+//!     Sphere::builder(a, 0.5).build().into(),
+//!     Sphere::builder(b, 0.5).build().into(),
+//!     Segments::new(vec![(a, b)]).into(),
+//! ];
+//!
+//! let paths = render(shapes).eye(Vector::new(0.0, -6.0, 2.0)).call();
+//! ```
+
+use crate::bounding_box::BBox;
+use crate::hit::Hit;
+use crate::path::Paths;
+use crate::ray::Ray;
+use crate::shape::{RenderArgs, Shape};
+use crate::vector::Vector;
+
+/// A set of straight line segments.
+///
+/// The segments are not solid: they are hidden by the shapes in front of
+/// them, but do not hide anything themselves, not even each other. There is
+/// no acceleration structure to build, so thousands of segments cost little
+/// more than their paths.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Ray, Segments, Shape, Vector};
+///
+/// let segments = Segments::new(vec![
+///     (Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0)),
+///     (Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 2.0, 0.0)),
+/// ]);
+/// assert_eq!(segments.bounding_box().max, Vector::new(1.0, 2.0, 0.0));
+///
+/// let ray = Ray::new(Vector::new(0.5, 0.0, 1.0), Vector::new(0.0, 0.0, -1.0));
+/// assert!(!segments.intersect(ray).is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Segments {
+    /// The start and end point of every segment.
+    pub segments: Vec<(Vector, Vector)>,
+}
+
+impl Segments {
+    /// Creates the shape drawing `segments`.
+    pub fn new(segments: Vec<(Vector, Vector)>) -> Self {
+        Segments { segments }
+    }
+}
+
+impl Shape for Segments {
+    fn bounding_box(&self) -> BBox {
+        let points: Vec<Vector> = self.segments.iter().flat_map(|&(a, b)| [a, b]).collect();
+        BBox::for_vectors(&points)
+    }
+
+    fn contains(&self, _v: Vector, _f: f64) -> bool {
+        false
+    }
+
+    fn intersect(&self, _r: Ray) -> Hit {
+        Hit::no_hit()
+    }
+
+    fn paths(&self, _args: &RenderArgs) -> Paths<Vector> {
+        let mut paths = Paths::with_capacity(2 * self.segments.len(), self.segments.len());
+        for &(a, b) in &self.segments {
+            paths.new_path().extend([a, b]);
+        }
+        paths
+    }
+
+    fn view_dependent(&self) -> bool {
+        false
+    }
+}
@@ -46,6 +46,7 @@ use crate::matrix::Matrix;
 use crate::path::Paths;
 use crate::ray::Ray;
 use crate::vector::Vector;
+use std::sync::Arc;
 
 /// The core trait for all renderable 3D geometry.
 ///
@@ -88,6 +89,16 @@ pub trait Shape {
     /// this might be the 12 edges. For a sphere, it could be latitude and
     /// longitude lines. Custom implementations can return any pattern.
     fn paths(&self, args: &RenderArgs) -> Paths<Vector>;
+
+    /// Returns whether [`paths`](Shape::paths) depends on the camera.
+    ///
+    /// A [`Scene`](crate::Scene) caches the paths of shapes returning `false`
+    /// and reuses them when rendering from another viewpoint. Shapes whose
+    /// paths follow the view, like sphere outlines, or are subdivided in
+    /// screen space must return `true`, which is the default.
+    fn view_dependent(&self) -> bool {
+        true
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -98,6 +109,11 @@ pub struct RenderArgs {
     pub width: f64,
     pub height: f64,
     pub step: f64,
+    /// Curved outlines stop subdividing at pieces spanning less than this
+    /// angle, in degrees, even if they are longer than `step` on screen. The
+    /// render functions default it to `2.0`, larger angles cap the number of
+    /// points on a full circle at `720 / min_arc_angle`.
+    pub min_arc_angle: f64,
 }
 
 /// Automatically implement `Shape` for references to shapes.
@@ -117,6 +133,33 @@ impl<T: Shape + ?Sized> Shape for &T {
     fn paths(&self, args: &RenderArgs) -> Paths<Vector> {
         (*self).paths(args)
     }
+
+    fn view_dependent(&self) -> bool {
+        (*self).view_dependent()
+    }
+}
+
+/// Automatically implement `Shape` for shared shapes.
+impl<T: Shape + ?Sized> Shape for Arc<T> {
+    fn bounding_box(&self) -> BBox {
+        (**self).bounding_box()
+    }
+
+    fn contains(&self, v: Vector, f: f64) -> bool {
+        (**self).contains(v, f)
+    }
+
+    fn intersect(&self, r: Ray) -> Hit {
+        (**self).intersect(r)
+    }
+
+    fn paths(&self, args: &RenderArgs) -> Paths<Vector> {
+        (**self).paths(args)
+    }
+
+    fn view_dependent(&self) -> bool {
+        (**self).view_dependent()
+    }
 }
 
 /// A shape that represents empty space.
@@ -142,6 +185,10 @@ impl Shape for EmptyShape {
     fn paths(&self, _args: &RenderArgs) -> Paths<Vector> {
         Paths::new()
     }
+
+    fn view_dependent(&self) -> bool {
+        false
+    }
 }
 
 /// A shape with a transformation matrix applied.
@@ -179,6 +226,11 @@ impl<T> TransformedShape<T> {
     ///
     /// The inverse matrix is computed automatically and cached for use
     /// in intersection and containment tests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is singular (e.g. a zero scale), since such a
+    /// transform cannot be ray traced.
     pub fn new(shape: T, matrix: Matrix) -> Self {
         let inverse = matrix.inverse();
         TransformedShape {
@@ -201,7 +253,14 @@ impl<T: Shape> Shape for TransformedShape<T> {
     fn intersect(&self, r: Ray) -> Hit {
         let (transformed_ray, scale) = self.inverse.mul_ray(r);
         let mut hit = self.shape.intersect(transformed_ray);
-        hit.t /= scale;
+        // Scaling a miss could bring its infinite distance below `INF`
+        if hit.is_ok() {
+            hit.t /= scale;
+        }
+        // Normals transform by the inverse transpose to stay perpendicular
+        if let Some(n) = hit.normal {
+            hit = hit.with_normal(self.inverse.transpose().mul_direction(n));
+        }
         hit
     }
 
@@ -215,4 +274,67 @@ impl<T: Shape> Shape for TransformedShape<T> {
             })
             .transform(&self.matrix)
     }
+
+    fn view_dependent(&self) -> bool {
+        self.shape.view_dependent()
+    }
+}
+
+/// A shape drawn with its paths but without occluding anything.
+///
+/// `Wireframe` passes the paths and bounding box of the wrapped shape
+/// through, but is never hit by a ray and contains no points. Its paths are
+/// still hidden by other solid shapes, yet the back of the shape shows
+/// through its front, e.g. to overlay a ghost outline on a solid render.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Cube, Primitive, Sphere, Vector, Wireframe, render};
+///
+/// // A sphere inside a see-through cube
+/// let cube = || -> Primitive {
+///     Cube::builder(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0)).build().into()
+/// };
+/// let sphere = Sphere::builder(Vector::new(0.0, 0.0, 0.0), 0.8).build();
+///
+/// let eye = Vector::new(4.0, 3.0, 2.0);
+/// let solid = render(vec![cube()]).eye(eye).call();
+/// let ghost = render::<Primitive>(vec![Wireframe::new(cube()).into(), sphere.into()]).eye(eye).call();
+/// // All 12 edges, partly hidden by the sphere, instead of the 9 visible ones
+/// assert!(ghost.total_length() > solid.total_length());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Wireframe<T> {
+    /// The underlying shape providing the paths.
+    pub shape: T,
+}
+
+impl<T> Wireframe<T> {
+    /// Wraps `shape` so it no longer occludes.
+    pub fn new(shape: T) -> Self {
+        Wireframe { shape }
+    }
+}
+
+impl<T: Shape> Shape for Wireframe<T> {
+    fn bounding_box(&self) -> BBox {
+        self.shape.bounding_box()
+    }
+
+    fn contains(&self, _v: Vector, _f: f64) -> bool {
+        false
+    }
+
+    fn intersect(&self, _r: Ray) -> Hit {
+        Hit::no_hit()
+    }
+
+    fn paths(&self, args: &RenderArgs) -> Paths<Vector> {
+        self.shape.paths(args)
+    }
+
+    fn view_dependent(&self) -> bool {
+        self.shape.view_dependent()
+    }
 }
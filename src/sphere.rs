@@ -21,7 +21,6 @@
 //! ```
 
 use crate::hit::Hit;
-use crate::matrix::Matrix;
 use crate::path::Paths;
 use crate::path::adaptive_arc;
 use crate::ray::Ray;
@@ -47,6 +46,14 @@ pub enum SphereTexture {
     RandomFuzz { seed: u64, num: usize, scale: f64 },
     /// Random concentric circles pattern (default num: 140)
     RandomCircles { seed: u64, num: usize },
+    /// Engraving-style hatching shaded from the direction `light` points to
+    /// (default density: 16)
+    ///
+    /// Lines run from the lit pole to the dark pole and only cover the part of
+    /// the sphere darker than a threshold. `density` lines start where
+    /// `normal · light < 0.5`, their number doubles below `0` and again below
+    /// `-0.5`, where rings around the light axis cross-hatch the deepest shadow.
+    Hatched { light: Vector, density: f64 },
 }
 
 #[bon]
@@ -84,6 +91,89 @@ impl SphereTexture {
     ) -> Self {
         SphereTexture::RandomCircles { seed, num }
     }
+
+    /// Create a hatched texture lit from the direction `light` points to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Sphere, SphereTexture, Vector, render};
+    ///
+    /// let light = Vector::new(1.0, 1.0, 1.0);
+    /// let sphere = Sphere::builder(Vector::new(0.0, 0.0, 0.0), 1.0)
+    ///     .texture(SphereTexture::hatched(light).density(12.0).call())
+    ///     .build();
+    ///
+    /// // Seen from the light, the sphere is lit where the hatching is sparse
+    /// let paths = render(vec![sphere.clone()]).eye(light.mul_scalar(4.0)).call();
+    /// let from_shadow = render(vec![sphere]).eye(light.mul_scalar(-4.0)).call();
+    /// let length = |paths: &larnt::Paths<Vector>| -> f64 {
+    ///     paths.iter_paths().flat_map(|p| p.windows(2)).map(|w| w[0].distance(w[1])).sum()
+    /// };
+    /// assert!(length(&paths) < length(&from_shadow));
+    /// ```
+    #[builder]
+    pub fn hatched(
+        #[builder(start_fn)] light: Vector,
+        #[builder(default = 16.0)] density: f64,
+    ) -> Self {
+        SphereTexture::Hatched { light, density }
+    }
+}
+
+impl SphereTexture {
+    /// Returns this texture with its seed drawn from `rng`.
+    ///
+    /// Random textures store a seed rather than a generator so that every
+    /// render of the same shape produces the same paths. Drawing those seeds
+    /// from one user-controlled stream makes a whole scene of randomized
+    /// shapes reproducible from a single seed. Textures without randomness
+    /// are returned unchanged and do not advance `rng`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Sphere, SphereTexture, Vector};
+    /// use rand::{SeedableRng, rngs::SmallRng};
+    ///
+    /// let fuzz = SphereTexture::random_fuzz(0).num(200).call();
+    /// let spheres = |seed| {
+    ///     let mut rng = SmallRng::seed_from_u64(seed);
+    ///     (0..10)
+    ///         .map(|i| {
+    ///             Sphere::builder(Vector::new(3.0 * i as f64, 0.0, 0.0), 1.0)
+    ///                 .texture(fuzz.reseed(&mut rng))
+    ///                 .build()
+    ///         })
+    ///         .collect::<Vec<_>>()
+    /// };
+    ///
+    /// let seed = |s: &Sphere| match s.texture {
+    ///     SphereTexture::RandomFuzz { seed, .. } => seed,
+    ///     _ => unreachable!(),
+    /// };
+    /// let (a, b) = (spheres(7), spheres(7));
+    /// assert!(a.iter().zip(&b).all(|(a, b)| seed(a) == seed(b)));
+    /// assert_ne!(seed(&a[0]), seed(&a[1]));
+    /// ```
+    pub fn reseed(self, rng: &mut impl Rng) -> Self {
+        match self {
+            SphereTexture::RandomEquators { n, .. } => SphereTexture::RandomEquators {
+                seed: rng.random(),
+                n,
+            },
+            SphereTexture::RandomFuzz { num, scale, .. } => SphereTexture::RandomFuzz {
+                seed: rng.random(),
+                num,
+                scale,
+            },
+            SphereTexture::RandomCircles { num, .. } => SphereTexture::RandomCircles {
+                seed: rng.random(),
+                num,
+            },
+            texture => texture,
+        }
+    }
 }
 
 /// A sphere defined by center and radius.
@@ -145,12 +235,11 @@ impl Shape for Sphere {
         if d > 0.0 {
             let d = d.sqrt();
             let t1 = -b - d;
-            if t1 > 1e-2 {
-                return Hit::new(t1);
-            }
             let t2 = -b + d;
-            if t2 > 1e-2 {
-                return Hit::new(t2);
+            for t in [t1, t2] {
+                if t > 1e-2 {
+                    return Hit::new(t).with_normal(r.position(t).sub(self.center));
+                }
             }
         }
         Hit::no_hit()
@@ -159,16 +248,15 @@ impl Shape for Sphere {
     fn paths(&self, args: &RenderArgs) -> Paths<Vector> {
         match self.texture {
             SphereTexture::Outline => self.paths_outline(args),
-            SphereTexture::LatLng { n, o } => self.paths_lat_lng(&args.screen_mat, args.step, n, o),
-            SphereTexture::RandomEquators { seed, n } => {
-                self.paths_random_equators(&args.screen_mat, args.step, n, seed)
-            }
+            SphereTexture::LatLng { n, o } => self.paths_lat_lng(args, n, o),
+            SphereTexture::RandomEquators { seed, n } => self.paths_random_equators(args, n, seed),
             SphereTexture::RandomFuzz { seed, num, scale } => {
                 self.paths_random_fuzz(num, scale, seed)
             }
             SphereTexture::RandomCircles { seed, num } => {
-                self.paths_random_circles(&args.screen_mat, args.step, num, seed)
+                self.paths_random_circles(args, num, seed)
             }
+            SphereTexture::Hatched { light, density } => self.paths_hatched(args, light, density),
         }
     }
 }
@@ -204,22 +292,13 @@ impl Sphere {
         let v = w.cross(u).normalize();
         let c = args.eye.add(w.mul_scalar(d));
 
-        adaptive_arc(
-            0.0,
-            PI * 2.,
-            r,
-            &(c, u, v),
-            &args.screen_mat,
-            args.step.powi(2),
-            &mut paths.new_path(),
-        );
+        adaptive_arc(0.0, PI * 2., r, &(c, u, v), args, &mut paths.new_path());
         paths
     }
 
     /// Latitude/longitude grid texture
-    fn paths_lat_lng(&self, screen_mat: &Matrix, step: f64, n: i32, o: i32) -> Paths<Vector> {
+    fn paths_lat_lng(&self, args: &RenderArgs, n: i32, o: i32) -> Paths<Vector> {
         let mut paths = Paths::new();
-        let step_sq = step.powi(2);
 
         // Latitude lines
         {
@@ -234,15 +313,7 @@ impl Sphere {
                 };
                 let (u, v) = (Vector::new(1., 0., 0.), Vector::new(0., 1., 0.));
 
-                adaptive_arc(
-                    0.0,
-                    PI * 2.0,
-                    r,
-                    &(c, u, v),
-                    screen_mat,
-                    step_sq,
-                    &mut paths.new_path(),
-                );
+                adaptive_arc(0.0, PI * 2.0, r, &(c, u, v), args, &mut paths.new_path());
                 lat += n;
             }
         }
@@ -259,15 +330,7 @@ impl Sphere {
                 };
                 let [alpha, beta] = [o, 180 - o].map(|x| radians(x as f64));
 
-                adaptive_arc(
-                    alpha,
-                    beta,
-                    r,
-                    &(c, u, v),
-                    screen_mat,
-                    step_sq,
-                    &mut paths.new_path(),
-                );
+                adaptive_arc(alpha, beta, r, &(c, u, v), args, &mut paths.new_path());
                 lng += n;
             }
         }
@@ -276,16 +339,9 @@ impl Sphere {
     }
 
     /// Random rotated equators (great circles)
-    fn paths_random_equators(
-        &self,
-        screen_mat: &Matrix,
-        step: f64,
-        n: usize,
-        seed: u64,
-    ) -> Paths<Vector> {
+    fn paths_random_equators(&self, args: &RenderArgs, n: usize, seed: u64) -> Paths<Vector> {
         let mut paths = Paths::new();
         let mut rng = SmallRng::seed_from_u64(seed);
-        let step_sq = step.powi(2);
         let (c, r) = (self.center, self.radius);
 
         for _ in 0..n {
@@ -294,15 +350,7 @@ impl Sphere {
                 (u, w.cross(u).normalize())
             };
 
-            adaptive_arc(
-                0.0,
-                PI * 2.0,
-                r,
-                &(c, u, v),
-                screen_mat,
-                step_sq,
-                &mut paths.new_path(),
-            );
+            adaptive_arc(0.0, PI * 2.0, r, &(c, u, v), args, &mut paths.new_path());
         }
 
         paths
@@ -325,18 +373,11 @@ impl Sphere {
     }
 
     /// Random concentric circles pattern
-    fn paths_random_circles(
-        &self,
-        screen_mat: &Matrix,
-        step: f64,
-        num: usize,
-        seed: u64,
-    ) -> Paths<Vector> {
+    fn paths_random_circles(&self, args: &RenderArgs, num: usize, seed: u64) -> Paths<Vector> {
         let mut paths = Paths::new();
         let mut rng = SmallRng::seed_from_u64(seed);
         let mut seen: Vec<Vector> = Vec::with_capacity(num);
         let mut radii: Vec<f64> = Vec::with_capacity(num);
-        let step_sq = step.powi(2);
 
         for _ in 0..num {
             let mut v: Vector;
@@ -377,19 +418,57 @@ impl Sphere {
                     (r, c)
                 };
 
+                adaptive_arc(0.0, PI * 2.0, r, &(c, p, q), args, &mut paths.new_path());
+                current_m *= 0.75;
+            }
+        }
+
+        paths
+    }
+
+    /// Hatching texture, denser where the surface faces away from the light
+    fn paths_hatched(&self, args: &RenderArgs, light: Vector, density: f64) -> Paths<Vector> {
+        let mut paths = Paths::new();
+        let (c, r) = (self.center, self.radius);
+        let n = density.round().max(1.0) as usize;
+
+        let w = light.normalize();
+        let u = w.cross(w.min_axis()).normalize();
+        let v = w.cross(u);
+
+        // Each tone doubles the lines, interleaving them with the lighter ones.
+        // A line at angle `a` from the equator has brightness `sin(a)`.
+        let thresholds = [0.5, 0.0, -0.5];
+        for (k, threshold) in thresholds.into_iter().enumerate() {
+            let count = n << k;
+            for j in (0..count).filter(|j| k == 0 || j % 2 == 1) {
+                let angle = 2.0 * PI * j as f64 / count as f64;
+                let dir = u.mul_scalar(angle.cos()).add(v.mul_scalar(angle.sin()));
                 adaptive_arc(
-                    0.0,
-                    PI * 2.0,
+                    -PI / 2.0,
+                    f64::asin(threshold),
                     r,
-                    &(c, p, q),
-                    screen_mat,
-                    step_sq,
+                    &(c, dir, w),
+                    args,
                     &mut paths.new_path(),
                 );
-                current_m *= 0.75;
             }
         }
 
+        // Rings around the light axis cross-hatch the deepest shadow
+        let darkest = f64::asin(thresholds[thresholds.len() - 1]);
+        for i in 1..=n / 2 {
+            let a = -PI / 2.0 + (darkest + PI / 2.0) * i as f64 / (n / 2 + 1) as f64;
+            adaptive_arc(
+                0.0,
+                PI * 2.0,
+                r * a.cos(),
+                &(c.add(w.mul_scalar(r * a.sin())), u, v),
+                args,
+                &mut paths.new_path(),
+            );
+        }
+
         paths
     }
 }
@@ -0,0 +1,316 @@
+//! Torus primitive.
+//!
+//! This module provides the [`Torus`] shape, a ring defined by a center, the
+//! axis it revolves around, a major radius (center to tube center) and a minor
+//! radius (tube radius).
+//!
+//! # Example
+//!
+//! ```
+//! use larnt::{Torus, TorusTexture, Vector, render};
+//!
+//! // A torus lying in the XY plane with the default grid texture
+//! let torus = Torus::builder(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0), 1.0, 0.25)
+//!     .build();
+//!
+//! // Or with a coarser grid
+//! let coarse = Torus::builder(Vector::new(0.0, 0.0, 1.0), Vector::new(1.0, 0.0, 0.0), 1.0, 0.25)
+//!     .texture(TorusTexture::grid().meridians(12).parallels(6).call())
+//!     .build();
+//!
+//! let paths = render(vec![torus, coarse]).eye(Vector::new(4.0, 3.0, 2.0)).call();
+//! ```
+
+use crate::bounding_box::BBox;
+use crate::hit::Hit;
+use crate::path::{Paths, adaptive_arc, adaptive_arc_inner};
+use crate::ray::Ray;
+use crate::shape::{RenderArgs, Shape};
+use crate::vector::Vector;
+use bon::{Builder, bon};
+use std::f64::consts::PI;
+
+/// Texture style for Torus shapes
+#[derive(Debug, Clone, Copy)]
+pub enum TorusTexture {
+    /// Grid of meridian circles (around the tube) and parallel circles
+    /// (around the axis) (default meridians: 36, parallels: 12)
+    Grid { meridians: usize, parallels: usize },
+}
+
+#[bon]
+impl TorusTexture {
+    /// Create a grid texture with the specified number of meridian and parallel circles.
+    #[builder]
+    pub fn grid(
+        #[builder(default = 36)] meridians: usize,
+        #[builder(default = 12)] parallels: usize,
+    ) -> Self {
+        TorusTexture::Grid {
+            meridians,
+            parallels,
+        }
+    }
+}
+
+impl Default for TorusTexture {
+    fn default() -> Self {
+        TorusTexture::grid().call()
+    }
+}
+
+/// A torus defined by center, axis, major radius and minor radius.
+///
+/// The default paths generated are a grid of meridian and parallel circles.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Torus, Vector};
+///
+/// // Torus around the Z axis with a ring radius of 2 and a tube radius of 0.5
+/// let torus = Torus::builder(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0), 2.0, 0.5)
+///     .build();
+/// ```
+///
+/// # Intersection
+///
+/// ```
+/// use larnt::{Ray, Shape, Torus, Vector};
+///
+/// let torus = Torus::builder(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0), 2.0, 0.5)
+///     .build();
+///
+/// // Down the axis, through the hole
+/// let hit = torus.intersect(Ray::new(Vector::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, -1.0)));
+/// assert!(!hit.is_ok());
+///
+/// // Into the outside of the tube, with the normal pointing away from the axis
+/// let hit = torus.intersect(Ray::new(Vector::new(5.0, 0.0, 0.0), Vector::new(-1.0, 0.0, 0.0)));
+/// assert!((hit.t - 2.5).abs() < 1e-9);
+/// assert!(hit.normal().unwrap().sub(Vector::new(1.0, 0.0, 0.0)).length() < 1e-9);
+///
+/// // A ray touching the top of the tube does not cross it, one just below
+/// // it hits the top with the normal pointing up
+/// let hit = torus.intersect(Ray::new(Vector::new(5.0, 0.0, 0.5), Vector::new(-1.0, 0.0, 0.0)));
+/// assert!(!hit.is_ok());
+/// let hit = torus.intersect(Ray::new(Vector::new(5.0, 0.0, 0.4999), Vector::new(-1.0, 0.0, 0.0)));
+/// assert!((hit.t - 2.99).abs() < 1e-3);
+/// assert!(hit.normal().unwrap().sub(Vector::new(0.0, 0.0, 1.0)).length() < 0.05);
+///
+/// // Inside the tube, but not in the hole or around the torus
+/// assert!(torus.contains(Vector::new(2.0, 0.0, 0.0), 0.0));
+/// assert!(torus.contains(Vector::new(0.0, -2.3, 0.2), 0.0));
+/// assert!(!torus.contains(Vector::new(0.0, 0.0, 0.0), 0.0));
+/// assert!(!torus.contains(Vector::new(3.0, 0.0, 0.0), 0.0));
+/// assert!(!torus.contains(Vector::new(2.0, 0.0, 0.6), 0.0));
+/// ```
+#[derive(Debug, Clone, Builder)]
+pub struct Torus {
+    /// The center point of the torus.
+    #[builder(start_fn)]
+    pub center: Vector,
+    /// The axis the tube revolves around.
+    #[builder(start_fn)]
+    pub axis: Vector,
+    /// The distance from the center to the center of the tube.
+    #[builder(start_fn)]
+    pub major_radius: f64,
+    /// The radius of the tube.
+    #[builder(start_fn)]
+    pub minor_radius: f64,
+    /// The texture style for the torus.
+    #[builder(default)]
+    pub texture: TorusTexture,
+    #[builder(skip = frame(axis))]
+    frame: (Vector, Vector, Vector),
+}
+
+/// Returns an orthonormal basis `(u, v, w)` where `w` is the normalized axis.
+fn frame(axis: Vector) -> (Vector, Vector, Vector) {
+    let w = axis.normalize();
+    let u = w.cross(w.min_axis()).normalize();
+    let v = w.cross(u).normalize();
+    (u, v, w)
+}
+
+impl Torus {
+    /// Converts a world-space vector to the torus' local frame (axis along +Z).
+    fn to_local(&self, v: Vector) -> Vector {
+        let (u, v_, w) = self.frame;
+        Vector::new(v.dot(u), v.dot(v_), v.dot(w))
+    }
+
+    /// Grid texture: meridian circles around the tube and parallel circles around the axis.
+    fn paths_grid(&self, args: &RenderArgs, meridians: usize, parallels: usize) -> Paths<Vector> {
+        let mut paths = Paths::new();
+        let (u, v, w) = self.frame;
+        let (big_r, r) = (self.major_radius, self.minor_radius);
+
+        // Meridians: circles around the tube
+        for i in 0..meridians {
+            let phi = 2.0 * PI * i as f64 / meridians as f64;
+            let radial = u.mul_scalar(phi.cos()).add(v.mul_scalar(phi.sin()));
+            let c = self.center.add(radial.mul_scalar(big_r));
+            adaptive_arc(
+                0.0,
+                PI * 2.0,
+                r,
+                &(c, radial, w),
+                args,
+                &mut paths.new_path(),
+            );
+        }
+
+        // Parallels: circles around the axis, the inner ones are concave
+        for i in 0..parallels {
+            let theta = 2.0 * PI * i as f64 / parallels as f64;
+            let c = self.center.add(w.mul_scalar(r * theta.sin()));
+            let radius = big_r + r * theta.cos();
+            let arc = if theta.cos() >= 0.0 {
+                adaptive_arc
+            } else {
+                adaptive_arc_inner
+            };
+            arc(
+                0.0,
+                PI * 2.0,
+                radius,
+                &(c, u, v),
+                args,
+                &mut paths.new_path(),
+            );
+        }
+
+        paths
+    }
+}
+
+impl Shape for Torus {
+    fn bounding_box(&self) -> BBox {
+        let w = self.frame.2;
+        let extent = Vector::new(
+            self.major_radius * (1.0 - w.x * w.x).max(0.0).sqrt(),
+            self.major_radius * (1.0 - w.y * w.y).max(0.0).sqrt(),
+            self.major_radius * (1.0 - w.z * w.z).max(0.0).sqrt(),
+        )
+        .add_scalar(self.minor_radius);
+        BBox::new(self.center.sub(extent), self.center.add(extent))
+    }
+
+    fn contains(&self, v: Vector, f: f64) -> bool {
+        let p = self.to_local(v.sub(self.center));
+        let q = (p.x * p.x + p.y * p.y).sqrt() - self.major_radius;
+        q * q + p.z * p.z <= (self.minor_radius + f).powi(2)
+    }
+
+    fn intersect(&self, r: Ray) -> Hit {
+        let (t_min, t_max) = self.bounding_box().intersect(r);
+        let (t_min, t_max) = (t_min.max(1e-3), t_max);
+        if t_max < t_min {
+            return Hit::no_hit();
+        }
+
+        let o = self.to_local(r.origin.sub(self.center));
+        let d = self.to_local(r.direction);
+        let (big_r2, r2) = (self.major_radius.powi(2), self.minor_radius.powi(2));
+
+        // (|p|² + R² - r²)² = 4R²(px² + py²) with p = o + t·d, expanded in t
+        let dd = d.dot(d);
+        let m = o.dot(d);
+        let k = o.dot(o) + big_r2 - r2;
+        let coeffs = [
+            k * k - 4.0 * big_r2 * (o.x * o.x + o.y * o.y),
+            4.0 * m * k - 8.0 * big_r2 * (o.x * d.x + o.y * d.y),
+            4.0 * m * m + 2.0 * k * dd - 4.0 * big_r2 * (d.x * d.x + d.y * d.y),
+            4.0 * m * dd,
+            dd * dd,
+        ];
+
+        real_roots(&coeffs, t_min, t_max)
+            .first()
+            .map_or_else(Hit::no_hit, |&t| {
+                // From the nearest point of the center circle to the hit
+                let p = o.add(d.mul_scalar(t));
+                let q = Vector::new(p.x, p.y, 0.0)
+                    .normalize()
+                    .mul_scalar(self.major_radius);
+                let n = p.sub(q);
+                let (u, v, w) = self.frame;
+                Hit::new(t).with_normal(
+                    u.mul_scalar(n.x)
+                        .add(v.mul_scalar(n.y))
+                        .add(w.mul_scalar(n.z)),
+                )
+            })
+    }
+
+    fn paths(&self, args: &RenderArgs) -> Paths<Vector> {
+        match self.texture {
+            TorusTexture::Grid {
+                meridians,
+                parallels,
+            } => self.paths_grid(args, meridians, parallels),
+        }
+    }
+}
+
+/// Evaluates the polynomial with ascending `coeffs` at `x`.
+fn eval_poly(coeffs: &[f64], x: f64) -> f64 {
+    coeffs.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+/// Returns the sorted real roots of the polynomial with ascending `coeffs` in `[lo, hi]`.
+///
+/// The roots of the derivative split `[lo, hi]` into monotonic intervals,
+/// each of which is then searched for a sign change by bisection.
+fn real_roots(coeffs: &[f64], lo: f64, hi: f64) -> Vec<f64> {
+    let n = coeffs.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    if n == 2 {
+        let t = -coeffs[0] / coeffs[1];
+        return if (lo..=hi).contains(&t) {
+            vec![t]
+        } else {
+            Vec::new()
+        };
+    }
+
+    let derivative: Vec<f64> = coeffs
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, c)| c * i as f64)
+        .collect();
+
+    let mut bounds = vec![lo];
+    bounds.extend(real_roots(&derivative, lo, hi));
+    bounds.push(hi);
+
+    let mut roots = Vec::new();
+    for w in bounds.windows(2) {
+        let (mut a, mut b) = (w[0], w[1]);
+        let (mut fa, fb) = (eval_poly(coeffs, a), eval_poly(coeffs, b));
+        if fa == 0.0 {
+            roots.push(a);
+            continue;
+        }
+        if fa.signum() == fb.signum() {
+            continue;
+        }
+        for _ in 0..64 {
+            let mid = (a + b) / 2.0;
+            let fm = eval_poly(coeffs, mid);
+            if fm.signum() == fa.signum() {
+                a = mid;
+                fa = fm;
+            } else {
+                b = mid;
+            }
+        }
+        roots.push((a + b) / 2.0);
+    }
+    roots
+}
@@ -5,6 +5,10 @@ use crate::ray::Ray;
 use crate::shape::Shape;
 use crate::vector::Vector;
 
+/// The default maximum number of shapes in a leaf, see
+/// [`Tree::with_max_leaf_size`].
+pub const MAX_LEAF_SIZE: usize = 8;
+
 #[derive(Debug, Clone)]
 struct BvhNode {
     pub bx: BBox,
@@ -42,6 +46,32 @@ impl<T: Shape> Tree<T> {
     }
 
     pub fn new(shapes: Vec<T>) -> Self {
+        Self::with_max_leaf_size(shapes, MAX_LEAF_SIZE)
+    }
+
+    /// Builds a tree whose leaves hold at most `max_leaf_size` shapes.
+    ///
+    /// Nodes up to that size are only split when the surface area heuristic
+    /// estimates the split to be cheaper to traverse, so a larger value gives
+    /// a shallower tree with more shapes to test per leaf. Values below 1 are
+    /// treated as 1. [`new`](Tree::new) uses [`MAX_LEAF_SIZE`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Sphere, Tree, Vector};
+    ///
+    /// let spheres: Vec<Sphere> = (0..100)
+    ///     .map(|i| Sphere::builder(Vector::new(i as f64 * 3.0, 0.0, 0.0), 1.0).build())
+    ///     .collect();
+    ///
+    /// let fine = Tree::with_max_leaf_size(spheres.clone(), 1);
+    /// let coarse = Tree::with_max_leaf_size(spheres, 32);
+    /// assert_eq!(fine.leaf_count(), 100);
+    /// assert!(coarse.leaf_count() < fine.leaf_count());
+    /// assert!(coarse.depth() <= fine.depth());
+    /// ```
+    pub fn with_max_leaf_size(shapes: Vec<T>, max_leaf_size: usize) -> Self {
         if shapes.is_empty() {
             return Tree {
                 nodes: Vec::new(),
@@ -68,7 +98,16 @@ impl<T: Shape> Tree<T> {
 
         let mut sah_right_boxes = vec![BBox::default(); len];
 
-        Self::build(&mut nodes, &mut prims, &mut sah_right_boxes, 0, 0, len);
+        let max_leaf_size = max_leaf_size.max(1);
+        Self::build(
+            &mut nodes,
+            &mut prims,
+            &mut sah_right_boxes,
+            max_leaf_size,
+            0,
+            0,
+            len,
+        );
 
         let sorted_shapes = prims.into_iter().map(|p| p.shape).collect();
 
@@ -78,12 +117,52 @@ impl<T: Shape> Tree<T> {
         }
     }
 
-    pub fn intersect(&self, r: Ray) -> Hit {
+    /// Returns the number of levels of the tree, 0 for an empty tree and 1
+    /// when all shapes are in a single leaf.
+    pub fn depth(&self) -> usize {
         if self.nodes.is_empty() {
-            return Hit::no_hit();
+            return 0;
         }
+        let mut depth = 0;
+        let mut stack = vec![(0, 1)];
+        while let Some((node_idx, level)) = stack.pop() {
+            let node = &self.nodes[node_idx];
+            depth = depth.max(level);
+            if !Self::is_leaf(node) {
+                stack.push((node.left_first, level + 1));
+                stack.push((node.left_first + 1, level + 1));
+            }
+        }
+        depth
+    }
+
+    /// Returns the number of leaves, the nodes holding shapes.
+    pub fn leaf_count(&self) -> usize {
+        self.nodes.iter().filter(|node| Self::is_leaf(node)).count()
+    }
+
+    fn is_leaf(node: &BvhNode) -> bool {
+        node.axis == Axis::None || node.count > 0
+    }
+
+    pub fn intersect(&self, r: Ray) -> Hit {
+        self.nearest(r).0
+    }
 
+    /// Returns the nearest hit along `r` and the index of the hit shape in
+    /// [`shapes`](Tree::shapes), or `None` if nothing is hit.
+    pub fn intersect_index(&self, r: Ray) -> Option<(Hit, usize)> {
+        let (hit, index) = self.nearest(r);
+        hit.is_ok().then_some((hit, index))
+    }
+
+    fn nearest(&self, r: Ray) -> (Hit, usize) {
         let mut closest_hit = Hit::no_hit();
+        let mut closest_index = 0;
+        if self.nodes.is_empty() {
+            return (closest_hit, closest_index);
+        }
+
         let mut stack = [0usize; 64];
         let mut stack_ptr = 1;
         stack[0] = 0;
@@ -112,6 +191,7 @@ impl<T: Shape> Tree<T> {
                         let hit = shape.intersect(r);
                         if hit.t < closest_hit.t {
                             closest_hit = hit;
+                            closest_index = node.left_first + i;
                         }
                     }
                     continue;
@@ -133,13 +213,14 @@ impl<T: Shape> Tree<T> {
             stack_ptr += 1;
         }
 
-        closest_hit
+        (closest_hit, closest_index)
     }
 
     fn build(
         nodes: &mut Vec<BvhNode>,
         prims: &mut [PrimInfo<T>],
         sah_right_boxes: &mut [BBox],
+        max_leaf_size: usize,
         node_idx: usize,
         start: usize,
         end: usize,
@@ -152,7 +233,7 @@ impl<T: Shape> Tree<T> {
         }
         nodes[node_idx].bx = parent_bx;
 
-        if count <= 2 {
+        if count <= max_leaf_size.min(2) {
             nodes[node_idx].left_first = start;
             nodes[node_idx].count = count;
             nodes[node_idx].axis = Axis::None;
@@ -227,7 +308,7 @@ impl<T: Shape> Tree<T> {
         let traversal_cost = parent_area * 0.125;
         let leaf_cost = (count as f64) * parent_area;
 
-        if best_cost + traversal_cost >= leaf_cost && count <= 8 {
+        if best_cost + traversal_cost >= leaf_cost && count <= max_leaf_size {
             nodes[node_idx].left_first = start;
             nodes[node_idx].count = count;
             return;
@@ -244,6 +325,7 @@ impl<T: Shape> Tree<T> {
             nodes,
             prims,
             sah_right_boxes,
+            max_leaf_size,
             left_child_idx,
             start,
             best_split_idx,
@@ -252,6 +334,7 @@ impl<T: Shape> Tree<T> {
             nodes,
             prims,
             sah_right_boxes,
+            max_leaf_size,
             left_child_idx + 1,
             best_split_idx,
             end,
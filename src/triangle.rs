@@ -59,7 +59,9 @@ impl Triangle {
             return Hit::no_hit();
         }
 
-        Hit::new(d)
+        // The front side is the one the vertices wind counterclockwise around
+        let n = Vector::new(e1x, e1y, e1z).cross(Vector::new(e2x, e2y, e2z));
+        Hit::new(d).with_normal(n)
     }
 }
 
@@ -86,4 +88,8 @@ impl Shape for Triangle {
             .extend([self.v1, self.v2, self.v3, self.v1]);
         paths
     }
+
+    fn view_dependent(&self) -> bool {
+        false
+    }
 }
@@ -0,0 +1,142 @@
+//! Tube shape.
+//!
+//! This module provides the [`Tube`] shape, a pipe of constant radius swept
+//! along a polyline or a cubic Bézier curve. Internally it is a chain of
+//! [`Capsule`] segments, so the joints are smooth and occlusion works across
+//! the whole tube.
+//!
+//! # Example
+//!
+//! ```
+//! use larnt::{Tube, Vector, render};
+//!
+//! let tube = Tube::bezier(
+//!     Vector::new(-2.0, 0.0, 0.0),
+//!     Vector::new(-1.0, 2.0, 1.0),
+//!     Vector::new(1.0, -2.0, 1.0),
+//!     Vector::new(2.0, 0.0, 0.0),
+//!     0.2,
+//!     32,
+//! );
+//!
+//! let paths = render(vec![tube]).eye(Vector::new(4.0, 3.0, 2.0)).call();
+//! ```
+
+use crate::bounding_box::BBox;
+use crate::capsule::Capsule;
+use crate::cylinder::align_z_axis;
+use crate::hit::Hit;
+use crate::path::Paths;
+use crate::ray::Ray;
+use crate::shape::{RenderArgs, Shape, TransformedShape};
+use crate::tree::Tree;
+use crate::vector::Vector;
+
+/// A tube of constant radius following a path.
+///
+/// The paths generated are the silhouette outlines of each segment.
+///
+/// # Example
+///
+/// ```
+/// use larnt::{Shape, Tube, Vector};
+///
+/// let points = vec![
+///     Vector::new(0.0, 0.0, 0.0),
+///     Vector::new(1.0, 0.0, 0.0),
+///     Vector::new(1.0, 2.0, 0.0),
+///     Vector::new(1.0, 2.0, -3.0),
+/// ];
+/// let tube = Tube::polyline(points.clone(), 0.25);
+///
+/// // The bounding box encloses every point expanded by the radius
+/// let bx = tube.bounding_box();
+/// for p in points {
+///     assert!(bx.contains(p.sub_scalar(0.25 - 1e-9)));
+///     assert!(bx.contains(p.add_scalar(0.25 - 1e-9)));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tube {
+    /// The radius of the tube.
+    pub radius: f64,
+    bx: BBox,
+    tree: Tree<TransformedShape<Capsule>>,
+}
+
+impl Tube {
+    /// Creates a tube through the given points.
+    ///
+    /// Consecutive duplicate points are skipped.
+    pub fn polyline(points: Vec<Vector>, radius: f64) -> Self {
+        let segments: Vec<TransformedShape<Capsule>> = points
+            .windows(2)
+            .filter(|w| w[0].distance(w[1]) > 0.0)
+            .map(|w| {
+                let z = w[1].sub(w[0]).length();
+                TransformedShape::new(
+                    Capsule::builder(radius, 0.0, z).build(),
+                    align_z_axis(w[0], w[1]),
+                )
+            })
+            .collect();
+
+        let bx = segments
+            .iter()
+            .map(|s| s.bounding_box())
+            .reduce(|a, b| a.extend(b))
+            .unwrap_or_default();
+
+        Tube {
+            radius,
+            bx,
+            tree: Tree::new(segments),
+        }
+    }
+
+    /// Creates a tube along the cubic Bézier curve with control points
+    /// `p0`..`p3`, approximated by `segments` straight segments.
+    pub fn bezier(
+        p0: Vector,
+        p1: Vector,
+        p2: Vector,
+        p3: Vector,
+        radius: f64,
+        segments: usize,
+    ) -> Self {
+        let segments = segments.max(1);
+        let points = (0..=segments)
+            .map(|i| {
+                let t = i as f64 / segments as f64;
+                let s = 1.0 - t;
+                p0.mul_scalar(s * s * s)
+                    .add(p1.mul_scalar(3.0 * s * s * t))
+                    .add(p2.mul_scalar(3.0 * s * t * t))
+                    .add(p3.mul_scalar(t * t * t))
+            })
+            .collect();
+        Self::polyline(points, radius)
+    }
+}
+
+impl Shape for Tube {
+    fn bounding_box(&self) -> BBox {
+        self.bx
+    }
+
+    fn contains(&self, v: Vector, f: f64) -> bool {
+        self.tree.shapes().iter().any(|s| s.contains(v, f))
+    }
+
+    fn intersect(&self, r: Ray) -> Hit {
+        self.tree.intersect(r)
+    }
+
+    fn paths(&self, args: &RenderArgs) -> Paths<Vector> {
+        let mut paths = Paths::new();
+        for segment in self.tree.shapes() {
+            paths.extend(segment.paths(args));
+        }
+        paths
+    }
+}
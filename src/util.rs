@@ -1,7 +1,9 @@
 //! Utility functions.
 //!
 //! This module provides utility functions for angle conversion, median computation,
-//! and parsing.
+//! parsing and contour tracing.
+
+use crate::path::Paths;
 
 /// Converts degrees to radians.
 ///
@@ -60,3 +62,46 @@ pub fn parse_floats(items: &[&str]) -> Vec<f64> {
         .map(|s| s.parse::<f64>().unwrap_or(0.0))
         .collect()
 }
+
+/// Traces the zero set of a sampled scalar field with marching squares.
+///
+/// `value(i, j)` is the sample at grid point `(i, j)` for `i, j` in `0..=n`,
+/// negative samples are inside. The returned polylines run through the crossed
+/// grid edges, each keyed by its lower grid point and direction (`0` along `i`,
+/// `1` along `j`), so callers can place the points by interpolating the edges.
+pub(crate) fn marching_squares(
+    n: usize,
+    value: impl Fn(usize, usize) -> f64,
+) -> Paths<(usize, usize, u8)> {
+    let mut segments: Paths<(usize, usize, u8)> = Paths::new();
+    for i in 0..n {
+        for j in 0..n {
+            let corners = [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)];
+            let inside = corners.map(|(i, j)| value(i, j) < 0.0);
+            let edges = [(i, j, 0), (i + 1, j, 1), (i, j + 1, 0), (i, j, 1)];
+            let crossed = [
+                inside[0] != inside[1],
+                inside[1] != inside[2],
+                inside[3] != inside[2],
+                inside[0] != inside[3],
+            ];
+            let crossing: Vec<_> = (0..4).filter(|&k| crossed[k]).map(|k| edges[k]).collect();
+            match crossing.len() {
+                2 => segments.new_path().extend([crossing[0], crossing[1]]),
+                4 => {
+                    let center = corners.iter().map(|&(i, j)| value(i, j)).sum::<f64>();
+                    let pairs = if (center < 0.0) == inside[0] {
+                        [(0, 1), (2, 3)]
+                    } else {
+                        [(0, 3), (1, 2)]
+                    };
+                    for (a, b) in pairs {
+                        segments.new_path().extend([edges[a], edges[b]]);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    segments.splice_exact()
+}
@@ -47,7 +47,27 @@ use crate::common::EPS;
 /// let sum = a + b;  // Vector addition
 /// let scaled = a * 2.0;  // Scalar multiplication
 /// ```
+///
+/// # Serialization
+///
+/// With the `serde` feature, a vector is (de)serialized as the array `[x, y, z]`.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use larnt::Vector;
+///
+/// let v = Vector::new(1.0, 2.5, -3.0);
+/// assert_eq!(serde_json::to_string(&v).unwrap(), "[1.0,2.5,-3.0]");
+/// assert_eq!(serde_json::from_str::<Vector>("[1.0,2.5,-3.0]").unwrap(), v);
+/// # }
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "[f64; 3]", into = "[f64; 3]")
+)]
 pub struct Vector {
     /// The x component.
     pub x: f64,
@@ -133,6 +153,55 @@ impl Vector {
         }
     }
 
+    /// Reflects this vector about the plane with the given unit `normal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::Vector;
+    ///
+    /// let v = Vector::new(1.0, -1.0, 0.0);
+    /// let r = v.reflect(Vector::new(0.0, 1.0, 0.0));
+    /// assert_eq!(r, Vector::new(1.0, 1.0, 0.0));
+    /// ```
+    pub fn reflect(&self, normal: Vector) -> Vector {
+        self.sub(normal.mul_scalar(2.0 * self.dot(normal)))
+    }
+
+    /// Returns the projection of this vector onto the direction of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::Vector;
+    ///
+    /// let v = Vector::new(2.0, 3.0, 4.0);
+    /// let p = v.project_onto(Vector::new(0.0, 0.0, 5.0));
+    /// assert_eq!(p, Vector::new(0.0, 0.0, 4.0));
+    /// ```
+    pub fn project_onto(&self, other: Vector) -> Vector {
+        other.mul_scalar(self.dot(other) / other.length_squared())
+    }
+
+    /// Returns the angle between this vector and another, in radians.
+    ///
+    /// The result is in `[0, π]` and stays accurate for nearly parallel
+    /// vectors, where `acos` of the dot product loses precision.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use larnt::{Vector, degrees};
+    ///
+    /// let a = Vector::new(1.0, 0.0, 0.0);
+    /// let b = Vector::new(1.0, 1.0, 0.0);
+    /// assert!((degrees(a.angle_between(b)) - 45.0).abs() < 1e-9);
+    /// assert_eq!(a.angle_between(a.mul_scalar(3.0)), 0.0);
+    /// ```
+    pub fn angle_between(&self, other: Vector) -> f64 {
+        self.cross(other).length().atan2(self.dot(other))
+    }
+
     pub fn add(&self, other: Vector) -> Vector {
         Vector {
             x: self.x + other.x,
@@ -314,3 +383,15 @@ impl std::hash::Hash for Vector {
 }
 
 impl Eq for Vector {}
+
+impl From<[f64; 3]> for Vector {
+    fn from([x, y, z]: [f64; 3]) -> Self {
+        Vector::new(x, y, z)
+    }
+}
+
+impl From<Vector> for [f64; 3] {
+    fn from(v: Vector) -> Self {
+        [v.x, v.y, v.z]
+    }
+}